@@ -0,0 +1,348 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{Ident, Member};
+
+use super::target::{ConvertType, TargetConstruct};
+
+/// How the errors of a conversion are reported
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ErrorMode {
+    /// `impl From`
+    Infallible,
+    /// `impl TryFrom` returning the first `more_convert::ConvertError`
+    First,
+    /// `impl TryFrom` returning every error in `more_convert::ConvertErrors`
+    Collect,
+}
+
+impl ErrorMode {
+    pub(crate) fn is_fallible(self) -> bool {
+        self != ErrorMode::Infallible
+    }
+
+    /// The type of `Self::Error`, `None` for infallible conversions
+    pub(crate) fn error_type(self) -> Option<TokenStream> {
+        match self {
+            ErrorMode::Infallible => None,
+            ErrorMode::First => Some(quote! { more_convert::ConvertError }),
+            ErrorMode::Collect => Some(quote! { more_convert::ConvertErrors }),
+        }
+    }
+}
+
+pub(crate) struct FieldToken {
+    pub target: TokenStream,
+    pub value: FieldValue,
+    /// `target` is a nested field assigned after the construction (`target_path`)
+    pub assigned: bool,
+}
+
+pub(crate) enum FieldValue {
+    Converted {
+        /// The path of the source field, used by conversion errors
+        source: String,
+        token: TokenStream,
+        is_result: bool,
+    },
+    /// A flattened struct of type `ty` constructed in place from its own fields
+    Nested {
+        ty: TokenStream,
+        fields: Vec<FieldToken>,
+    },
+}
+
+impl FieldToken {
+    pub(crate) fn converted(
+        target: TokenStream,
+        source: String,
+        token: TokenStream,
+        is_result: bool,
+    ) -> Self {
+        Self {
+            target,
+            value: FieldValue::Converted {
+                source,
+                token,
+                is_result,
+            },
+            assigned: false,
+        }
+    }
+}
+
+pub(crate) struct BodyGenerator<'a> {
+    pub from: &'a ConvertType,
+    pub into: &'a ConvertType,
+    pub mode: ErrorMode,
+    /// The base of the struct update syntax, e.g. `Default::default()` of `default_rest`
+    pub rest: Option<TokenStream>,
+    /// `constructor` or `builder`, replacing the struct literal
+    pub construct: Option<&'a TargetConstruct>,
+}
+
+impl BodyGenerator<'_> {
+    /// Generates the error value of `Self::Error` for the source at `path`
+    pub(crate) fn gen_error(&self, path: &str, error: TokenStream) -> TokenStream {
+        let Self { from, into, .. } = self;
+        match self.mode {
+            ErrorMode::Infallible | ErrorMode::First => quote! {
+                more_convert::ConvertError::new(stringify!(#from), stringify!(#into), #path, #error)
+            },
+            ErrorMode::Collect => {
+                let errors = Ident::new("errors", Span::mixed_site());
+                quote! {{
+                    let mut #errors = more_convert::ConvertErrors::default();
+                    #errors.push(stringify!(#from), stringify!(#into), #path, #error);
+                    #errors
+                }}
+            }
+        }
+    }
+
+    /// Generates the conversion chained through `hops` (`via`) by their `Into` impls,
+    /// or by their `TryInto` impls in fallible conversions.
+    ///
+    /// A missing impl is reported at the type of the hop (the target for the last one),
+    /// and the errors of a hop are reported at the name of its type.
+    pub(crate) fn gen_via(&self, hops: &[ConvertType]) -> TokenStream {
+        let value = Ident::new("value", Span::call_site());
+        let error = Ident::new("error", Span::mixed_site());
+        let fallible = self.mode.is_fallible();
+        let hop = |ty: &ConvertType, target: TokenStream| {
+            // the function and its argument are located at the type, which rustc reports
+            // for a missing impl, but the call stays generated code for the lints
+            let span = ty.span();
+            let argument = Ident::new("value", Span::call_site().located_at(span));
+            if fallible {
+                let map_err = self.gen_error(&ty.to_string(), quote! { #error });
+                let function = quote_spanned! {span=> std::convert::TryInto::<#target>::try_into };
+                let call = quote_spanned! {span=> (#function(#argument)) };
+                quote! { #call.map_err(|#error| #map_err) }
+            } else {
+                let function = quote_spanned! {span=> std::convert::Into::<#target>::into };
+                quote! { #function(#argument) }
+            }
+        };
+
+        // `try_*` targets chain infallible hops through `TryInto` on purpose
+        let allow = fallible.then(|| quote! { #[allow(clippy::unnecessary_fallible_conversions)] });
+        let question = fallible.then(|| quote! { ? });
+        let steps = hops.iter().map(|ty| {
+            let step = hop(ty, ty.to_token_stream());
+            quote! { #allow let #value = #step #question; }
+        });
+        let last = hop(self.into, quote! { Self });
+        quote! {
+            #( #steps )*
+            #allow let #value = #last;
+            #value
+        }
+    }
+
+    /// Generates the expression constructing `ctor` (e.g. `Self` or `Self::Variant`) from the fields.
+    ///
+    /// For fallible conversions the expression evaluates to `Result<Self, Self::Error>`.
+    pub(crate) fn gen_construct(&self, ctor: &TokenStream, fields: &[FieldToken]) -> TokenStream {
+        match self.mode {
+            ErrorMode::Infallible => {
+                let fields = gen_fields(fields, &mut |_, token, _| token.clone());
+                self.gen_literal(ctor, fields)
+            }
+            ErrorMode::First => self.gen_first_error(ctor, fields),
+            ErrorMode::Collect => self.gen_collect_errors(ctor, fields),
+        }
+    }
+
+    /// Returns on the first error, wrapped in `more_convert::ConvertError`
+    fn gen_first_error(&self, ctor: &TokenStream, fields: &[FieldToken]) -> TokenStream {
+        let error = Ident::new("error", Span::mixed_site());
+        let fields = gen_fields(fields, &mut |source, token, is_result| {
+            if is_result {
+                let map_err = self.gen_error(source, quote! { #error });
+                quote! { (#token).map_err(|#error| #map_err)? }
+            } else {
+                token.clone()
+            }
+        });
+
+        let construct = self.gen_literal(ctor, fields);
+        quote! {
+            std::result::Result::Ok(#construct)
+        }
+    }
+
+    /// Evaluates every field and collects all errors in `more_convert::ConvertErrors`
+    fn gen_collect_errors(&self, ctor: &TokenStream, fields: &[FieldToken]) -> TokenStream {
+        let Self { from, into, .. } = self;
+        let errors = Ident::new("errors", Span::mixed_site());
+        let error = Ident::new("error", Span::mixed_site());
+
+        let mut lets = Vec::with_capacity(fields.len());
+        let mut results = Vec::new();
+        let fields = gen_fields(fields, &mut |source, token, is_result| {
+            let local = format_ident!("field_{}", lets.len(), span = Span::mixed_site());
+            if is_result {
+                lets.push(quote! {
+                    let #local = (#token)
+                        .map_err(|#error| {
+                            #errors.push(stringify!(#from), stringify!(#into), #source, #error)
+                        })
+                        .ok();
+                });
+                results.push(local.clone());
+            } else {
+                lets.push(quote! { let #local = #token; });
+            }
+            local.into_token_stream()
+        });
+
+        let unwrap = (!results.is_empty()).then(|| {
+            quote! {
+                let ( #( std::option::Option::Some(#results), )* ) = ( #( #results, )* ) else {
+                    return std::result::Result::Err(#errors);
+                };
+            }
+        });
+
+        let construct = self.gen_literal(ctor, fields);
+        quote! {{
+            let mut #errors = more_convert::ConvertErrors::default();
+            #( #lets )*
+            #unwrap
+            std::result::Result::Ok(#construct)
+        }}
+    }
+
+    /// Generates the struct literal (or the call of `constructor` or `builder`),
+    /// then assigns the nested fields of `target_path`
+    fn gen_literal(
+        &self,
+        ctor: &TokenStream,
+        (fields, assignments): (Vec<(TokenStream, TokenStream)>, Vec<TokenStream>),
+    ) -> TokenStream {
+        let literal = match self.construct {
+            None => {
+                let rest = self.gen_rest();
+                let fields = fields
+                    .iter()
+                    .map(|(target, value)| quote! { #target: #value });
+                quote! {
+                    #ctor {
+                        #( #fields, )*
+                        #rest
+                    }
+                }
+            }
+            Some(TargetConstruct::Constructor { expr, fallible }) => {
+                // the arguments of the constructor refer to the fields by name,
+                // and to the fields of tuple structs by `_0`, `_1`, ..
+                let lets = fields.iter().enumerate().map(|(i, (target, value))| {
+                    let local = constructor_local(i, target);
+                    quote! { let #local = #value; }
+                });
+                let call = self.gen_try_construct(quote! { #expr }, "(constructor)", *fallible);
+                quote! {{
+                    #( #lets )*
+                    #call
+                }}
+            }
+            Some(TargetConstruct::Builder { fallible }) => {
+                let setters = fields
+                    .iter()
+                    .map(|(target, value)| quote! { .#target(#value) });
+                let build = quote! { <#ctor>::builder() #( #setters )* .build() };
+                self.gen_try_construct(build, "(builder)", *fallible)
+            }
+        };
+        if assignments.is_empty() {
+            return literal;
+        }
+
+        let target = Ident::new("target", Span::mixed_site());
+        quote! {{
+            let mut #target = #literal;
+            #( #target.#assignments; )*
+            #target
+        }}
+    }
+
+    /// Propagates the error of a fallible `constructor` or `builder` like that of a field at `path`
+    fn gen_try_construct(&self, construct: TokenStream, path: &str, fallible: bool) -> TokenStream {
+        if !fallible {
+            return construct;
+        }
+        let error = Ident::new("error", Span::mixed_site());
+        let target = Ident::new("target", Span::mixed_site());
+        let map_err = self.gen_error(path, quote! { #error });
+        // not `?`, which would be wrapped in a needless `Ok`
+        quote! {
+            match #construct {
+                std::result::Result::Ok(#target) => #target,
+                std::result::Result::Err(#error) => return std::result::Result::Err(#map_err),
+            }
+        }
+    }
+
+    fn gen_rest(&self) -> Option<TokenStream> {
+        self.rest.as_ref().map(|rest| quote! { ..#rest })
+    }
+}
+
+/// The name of the `i`th field `target` in the arguments of `constructor`
+pub(crate) fn constructor_local(i: usize, target: &TokenStream) -> Ident {
+    match syn::parse2(target.clone()) {
+        Ok(Member::Named(ident)) => ident,
+        Ok(Member::Unnamed(index)) => format_ident!("_{}", index.index),
+        Err(_) => format_ident!("_{}", i),
+    }
+}
+
+/// Generates `(target, value)` for every field, `leaf` generates the value of a converted field
+/// from its source path, its expression and whether it evaluates to a `Result`.
+///
+/// Flattened structs are constructed in place, so the errors of their fields
+/// are handled like those of the other fields.
+/// The assigned fields are generated as `target = value` in the second list.
+fn gen_fields(
+    fields: &[FieldToken],
+    leaf: &mut impl FnMut(&str, &TokenStream, bool) -> TokenStream,
+) -> (Vec<(TokenStream, TokenStream)>, Vec<TokenStream>) {
+    let mut tokens = Vec::with_capacity(fields.len());
+    let mut assignments = Vec::new();
+    for FieldToken {
+        target,
+        value,
+        assigned,
+    } in fields
+    {
+        let value = match value {
+            FieldValue::Converted {
+                source,
+                token,
+                is_result,
+            } => leaf(source, token, *is_result),
+            FieldValue::Nested { ty, fields } => {
+                // `target_path` is not supported in flattened structs
+                let (fields, _) = gen_fields(fields, leaf);
+                let fields = fields
+                    .iter()
+                    .map(|(target, value)| quote! { #target: #value });
+                // a struct literal needs a path, and `ty` may be a projection
+                quote! {
+                    {
+                        type __Flattened = #ty;
+                        __Flattened {
+                            #( #fields, )*
+                        }
+                    }
+                }
+            }
+        };
+        if *assigned {
+            assignments.push(quote! { #target = #value });
+        } else {
+            tokens.push((target.clone(), value));
+        }
+    }
+    (tokens, assignments)
+}
@@ -0,0 +1,449 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Expr, GenericArgument, Ident, PathArguments, Type};
+
+use super::field_arg::SourceAccess;
+
+/// The pointer types converted through their content
+#[derive(Clone, Copy)]
+pub(crate) enum Pointer {
+    Box,
+    Rc,
+    Arc,
+}
+
+impl Pointer {
+    fn path(self) -> TokenStream {
+        match self {
+            Pointer::Box => quote! { std::boxed::Box },
+            Pointer::Rc => quote! { std::rc::Rc },
+            Pointer::Arc => quote! { std::sync::Arc },
+        }
+    }
+}
+
+/// How the leaves of a [`Shape`] are converted instead of `Into`,
+/// set by `parse`, `display`, `cast`, `checked`, `saturating` or `round`
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LeafConversion {
+    /// `str::parse`
+    Parse,
+    /// `ToString::to_string`
+    Display,
+    /// `as`
+    Cast,
+    /// `TryFrom`
+    Checked,
+    /// `more_convert::SaturatingFrom`
+    Saturating,
+    /// `more_convert::RoundFrom`
+    Round,
+}
+
+impl LeafConversion {
+    pub(crate) fn from_ident(ident: &Ident) -> Option<Self> {
+        Some(match ident.to_string().as_str() {
+            "parse" => LeafConversion::Parse,
+            "display" => LeafConversion::Display,
+            "cast" => LeafConversion::Cast,
+            "checked" => LeafConversion::Checked,
+            "saturating" => LeafConversion::Saturating,
+            "round" => LeafConversion::Round,
+            _ => return None,
+        })
+    }
+
+    /// `parse` and `checked` make the conversion fallible
+    pub(crate) fn is_fallible(self) -> bool {
+        matches!(self, LeafConversion::Parse | LeafConversion::Checked)
+    }
+}
+
+/// The shape of the type of a field, walked by the default conversion
+/// to convert the elements of containers one by one.
+///
+/// Containers are recognized by the last segment of their path,
+/// `#[convert(container = "...")]` gives the shape of a type alias.
+/// `Rc` and `Arc` are leaves unless the hint walks into them,
+/// because rebuilding them would copy the shared content.
+pub(crate) enum Shape<'a> {
+    /// Converted with `Into` or `TryInto`, the type is `None` for `_`
+    Leaf(Option<&'a Type>),
+    Option(Box<Shape<'a>>),
+    /// `Vec`, `VecDeque`, `LinkedList`, `HashSet`, `BTreeSet` and `BinaryHeap`
+    Seq(Box<Shape<'a>>),
+    /// `HashMap` and `BTreeMap`
+    Map(Box<Shape<'a>>, Box<Shape<'a>>),
+    Pointer(Pointer, Box<Shape<'a>>),
+    Array(Box<Shape<'a>>, &'a Expr),
+    Result(Box<Shape<'a>>, Box<Shape<'a>>),
+}
+
+impl<'a> Shape<'a> {
+    /// The shape of the type of a field
+    pub(crate) fn new(ty: &'a Type) -> Self {
+        Self::walk(ty, false)
+    }
+
+    /// The shape given by `container`, which walks into `Rc` and `Arc`
+    pub(crate) fn hinted(ty: &'a Type) -> Self {
+        Self::walk(ty, true)
+    }
+
+    fn walk(ty: &'a Type, shared: bool) -> Self {
+        match ty {
+            Type::Infer(_) => Shape::Leaf(None),
+            Type::Paren(paren) => Shape::walk(&paren.elem, shared),
+            Type::Group(group) => Shape::walk(&group.elem, shared),
+            Type::Array(array) => {
+                Shape::Array(Box::new(Shape::walk(&array.elem, shared)), &array.len)
+            }
+            Type::Path(path) if path.qself.is_none() => {
+                let Some(segment) = path.path.segments.last() else {
+                    return Shape::Leaf(Some(ty));
+                };
+                let args = match &segment.arguments {
+                    PathArguments::AngleBracketed(args) => args
+                        .args
+                        .iter()
+                        .filter_map(|arg| match arg {
+                            GenericArgument::Type(ty) => Some(ty),
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                // a missing argument is `_`, e.g. `container = "Vec"`
+                let arg = |i: usize| {
+                    Box::new(
+                        args.get(i)
+                            .map_or(Shape::Leaf(None), |ty| Shape::walk(ty, shared)),
+                    )
+                };
+
+                match segment.ident.to_string().as_str() {
+                    "Option" => Shape::Option(arg(0)),
+                    "Vec" | "VecDeque" | "LinkedList" | "HashSet" | "BTreeSet" | "BinaryHeap" => {
+                        Shape::Seq(arg(0))
+                    }
+                    "HashMap" | "BTreeMap" => Shape::Map(arg(0), arg(1)),
+                    "Result" => Shape::Result(arg(0), arg(1)),
+                    "Box" | "Rc" | "Arc" if args.first().is_some_and(|ty| is_unsized(ty)) => {
+                        Shape::Leaf(Some(ty))
+                    }
+                    "Box" => Shape::Pointer(Pointer::Box, arg(0)),
+                    "Rc" if shared => Shape::Pointer(Pointer::Rc, arg(0)),
+                    "Arc" if shared => Shape::Pointer(Pointer::Arc, arg(0)),
+                    _ => Shape::Leaf(Some(ty)),
+                }
+            }
+            _ => Shape::Leaf(Some(ty)),
+        }
+    }
+
+    /// The types converted with `Into` or `TryInto`, and whether they are cloned
+    /// out of a shared pointer (`Rc` or `Arc`) when `access` is not by reference
+    pub(crate) fn leaves(&self, access: SourceAccess) -> Vec<(&'a Type, bool)> {
+        let mut leaves = Vec::new();
+        self.collect_leaves(access != SourceAccess::Ref, false, &mut leaves);
+        leaves
+    }
+
+    fn collect_leaves(&self, owned: bool, shared: bool, leaves: &mut Vec<(&'a Type, bool)>) {
+        match self {
+            Shape::Leaf(ty) => leaves.extend(ty.map(|ty| (ty, shared))),
+            Shape::Option(inner) | Shape::Seq(inner) | Shape::Array(inner, _) => {
+                inner.collect_leaves(owned, shared, leaves)
+            }
+            Shape::Pointer(pointer, inner) => {
+                let shared = shared || (owned && !matches!(pointer, Pointer::Box));
+                inner.collect_leaves(owned, shared, leaves)
+            }
+            Shape::Map(first, second) | Shape::Result(first, second) => {
+                first.collect_leaves(owned, shared, leaves);
+                second.collect_leaves(owned, shared, leaves);
+            }
+        }
+    }
+}
+
+/// `Box<dyn Trait>`, `Box<str>` and `Box<[T]>` can't be moved out of the pointer
+fn is_unsized(ty: &Type) -> bool {
+    match ty {
+        Type::TraitObject(_) | Type::Slice(_) => true,
+        Type::Path(path) => path.path.is_ident("str"),
+        _ => false,
+    }
+}
+
+/// Generates the default conversion of `source`, walking the containers of `shape`.
+///
+/// In a fallible conversion this uses `TryInto` and evaluates to a `Result`,
+/// the errors of maps and `Result` are boxed because their two sides may fail differently.
+/// `leaf` replaces `Into` and `TryInto` on the leaves,
+/// and `context` replaces them with `ConvertWith` and `TryConvertWith` passing `ctx`.
+/// Unless `access` is [`SourceAccess::Owned`], `source` is a reference to the field.
+pub(crate) fn gen_conversion(
+    shape: &Shape,
+    source: &TokenStream,
+    fallible: bool,
+    access: SourceAccess,
+    leaf: Option<LeafConversion>,
+    context: bool,
+) -> TokenStream {
+    let walk = Walk {
+        fallible,
+        leaf,
+        context,
+    };
+    match access {
+        // the leaf conversions only borrow the field
+        SourceAccess::Owned if leaf.is_some() => gen_ref(shape, &quote! { &#source }, walk),
+        SourceAccess::Owned => gen_owned(shape, source, walk),
+        SourceAccess::Clone => {
+            gen_owned(shape, &quote! { std::clone::Clone::clone(#source) }, walk)
+        }
+        SourceAccess::Ref => gen_ref(shape, source, walk),
+    }
+}
+
+/// The options of [`gen_conversion`] passed down the walk of a shape
+#[derive(Clone, Copy)]
+struct Walk {
+    fallible: bool,
+    leaf: Option<LeafConversion>,
+    context: bool,
+}
+
+fn gen_owned(shape: &Shape, source: &TokenStream, walk: Walk) -> TokenStream {
+    let item = Ident::new("item", Span::mixed_site());
+    match shape {
+        Shape::Leaf(_) => gen_leaf(source, walk),
+        Shape::Option(inner) => {
+            let inner = gen_owned(inner, &quote! { #item }, walk);
+            gen_transpose(quote! { (#source).map(|#item| #inner) }, walk.fallible)
+        }
+        Shape::Seq(inner) => {
+            let inner = gen_owned(inner, &quote! { #item }, walk);
+            gen_collect(
+                quote! { std::iter::IntoIterator::into_iter(#source).map(|#item| #inner) },
+                walk.fallible,
+            )
+        }
+        Shape::Map(key, value) => gen_map(
+            quote! { std::iter::IntoIterator::into_iter(#source) },
+            key,
+            value,
+            walk,
+            gen_owned,
+        ),
+        Shape::Pointer(pointer, inner) => {
+            let path = pointer.path();
+            let content = match pointer {
+                Pointer::Box => quote! { *#source },
+                // the content is cloned if it is shared
+                Pointer::Rc | Pointer::Arc => {
+                    let shared = Ident::new("shared", Span::mixed_site());
+                    quote! {
+                        #path::try_unwrap(#source)
+                            .unwrap_or_else(|#shared| std::clone::Clone::clone(&*#shared))
+                    }
+                }
+            };
+            gen_pointer(path, gen_owned(inner, &content, walk), walk.fallible)
+        }
+        Shape::Array(inner, len) => {
+            let inner = gen_owned(inner, &quote! { #item }, walk);
+            if walk.fallible {
+                gen_array(
+                    quote! { std::iter::IntoIterator::into_iter(#source).map(|#item| #inner) },
+                    len,
+                )
+            } else {
+                quote! { (#source).map(|#item| #inner) }
+            }
+        }
+        Shape::Result(ok, err) => gen_result(quote! { #source }, ok, err, walk, gen_owned),
+    }
+}
+
+/// `source` is a reference, converted with `From<&T>`
+fn gen_ref(shape: &Shape, source: &TokenStream, walk: Walk) -> TokenStream {
+    let item = Ident::new("item", Span::mixed_site());
+    match shape {
+        Shape::Leaf(_) => gen_leaf(source, walk),
+        Shape::Option(inner) => {
+            let inner = gen_ref(inner, &quote! { #item }, walk);
+            gen_transpose(
+                quote! { (#source).as_ref().map(|#item| #inner) },
+                walk.fallible,
+            )
+        }
+        Shape::Seq(inner) => {
+            let inner = gen_ref(inner, &quote! { #item }, walk);
+            gen_collect(
+                quote! { (#source).iter().map(|#item| #inner) },
+                walk.fallible,
+            )
+        }
+        Shape::Map(key, value) => gen_map(quote! { (#source).iter() }, key, value, walk, gen_ref),
+        Shape::Pointer(pointer, inner) => {
+            let inner = gen_ref(inner, &quote! { &**#source }, walk);
+            gen_pointer(pointer.path(), inner, walk.fallible)
+        }
+        Shape::Array(inner, len) => {
+            let inner = gen_ref(inner, &quote! { #item }, walk);
+            if walk.fallible {
+                gen_array(quote! { (#source).iter().map(|#item| #inner) }, len)
+            } else {
+                let index = Ident::new("index", Span::mixed_site());
+                quote! {
+                    std::array::from_fn::<_, { #len }, _>(|#index| {
+                        let #item = &(#source)[#index];
+                        #inner
+                    })
+                }
+            }
+        }
+        Shape::Result(ok, err) => gen_result(quote! { #source }, ok, err, walk, gen_ref),
+    }
+}
+
+fn gen_into(source: &TokenStream, walk: Walk) -> TokenStream {
+    // `ctx` is the parameter of `convert_with`
+    let ctx = Ident::new("ctx", Span::call_site());
+    match (walk.context, walk.fallible) {
+        (false, false) => quote! { std::convert::Into::into(#source) },
+        (false, true) => quote! { std::convert::TryInto::try_into(#source) },
+        (true, false) => quote! { more_convert::ConvertWith::convert_with(#source, #ctx) },
+        (true, true) => quote! { more_convert::TryConvertWith::try_convert_with(#source, #ctx) },
+    }
+}
+
+/// `source` is a reference with a leaf conversion, see [`gen_conversion`]
+fn gen_leaf(source: &TokenStream, walk: Walk) -> TokenStream {
+    let token = match walk.leaf {
+        None => return gen_into(source, walk),
+        Some(LeafConversion::Parse) => return quote! { str::parse(#source) },
+        Some(LeafConversion::Checked) => {
+            return quote! { std::convert::TryFrom::try_from(*#source) }
+        }
+        Some(LeafConversion::Display) => quote! { std::string::ToString::to_string(#source) },
+        Some(LeafConversion::Cast) => quote! { (*#source) as _ },
+        Some(LeafConversion::Saturating) => {
+            quote! { more_convert::SaturatingFrom::saturating_from(*#source) }
+        }
+        Some(LeafConversion::Round) => quote! { more_convert::RoundFrom::round_from(*#source) },
+    };
+    if walk.fallible {
+        quote! { std::result::Result::<_, std::convert::Infallible>::Ok(#token) }
+    } else {
+        token
+    }
+}
+
+fn gen_transpose(option: TokenStream, fallible: bool) -> TokenStream {
+    if fallible {
+        quote! { #option.transpose() }
+    } else {
+        option
+    }
+}
+
+fn gen_collect(iter: TokenStream, fallible: bool) -> TokenStream {
+    if fallible {
+        quote! { #iter.collect::<std::result::Result<_, _>>() }
+    } else {
+        quote! { #iter.collect() }
+    }
+}
+
+fn gen_pointer(path: TokenStream, inner: TokenStream, fallible: bool) -> TokenStream {
+    if fallible {
+        quote! { (#inner).map(#path::new) }
+    } else {
+        quote! { #path::new(#inner) }
+    }
+}
+
+type Generator = fn(&Shape, &TokenStream, Walk) -> TokenStream;
+
+fn gen_map(
+    iter: TokenStream,
+    key: &Shape,
+    value: &Shape,
+    walk: Walk,
+    gen: Generator,
+) -> TokenStream {
+    let key_ident = Ident::new("key", Span::mixed_site());
+    let value_ident = Ident::new("value", Span::mixed_site());
+    let key = gen(key, &quote! { #key_ident }, walk);
+    let value = gen(value, &quote! { #value_ident }, walk);
+    if walk.fallible {
+        let box_error = box_error();
+        quote! {
+            #iter
+                .map(|(#key_ident, #value_ident)| -> std::result::Result<_, #box_error> {
+                    std::result::Result::Ok((#key?, #value?))
+                })
+                .collect::<std::result::Result<_, _>>()
+        }
+    } else {
+        quote! {
+            #iter.map(|(#key_ident, #value_ident)| (#key, #value)).collect()
+        }
+    }
+}
+
+/// Collects the converted elements into an array of length `len`
+fn gen_array(iter: TokenStream, len: &Expr) -> TokenStream {
+    let items = Ident::new("items", Span::mixed_site());
+    let array = Ident::new("array", Span::mixed_site());
+    quote! {
+        #iter
+            .collect::<std::result::Result<std::vec::Vec<_>, _>>()
+            .map(|#items| match <[_; #len]>::try_from(#items) {
+                std::result::Result::Ok(#array) => #array,
+                std::result::Result::Err(_) => unreachable!("the length is checked by the type"),
+            })
+    }
+}
+
+fn gen_result(
+    source: TokenStream,
+    ok: &Shape,
+    err: &Shape,
+    walk: Walk,
+    gen: Generator,
+) -> TokenStream {
+    let item = Ident::new("item", Span::mixed_site());
+    let error = Ident::new("error", Span::mixed_site());
+    let ok = gen(ok, &quote! { #item }, walk);
+    let err = gen(err, &quote! { #error }, walk);
+    if walk.fallible {
+        let box_error = box_error();
+        quote! {
+            match #source {
+                std::result::Result::Ok(#item) => (#ok)
+                    .map(std::result::Result::Ok)
+                    .map_err(std::convert::Into::<#box_error>::into),
+                std::result::Result::Err(#error) => (#err)
+                    .map(std::result::Result::Err)
+                    .map_err(std::convert::Into::<#box_error>::into),
+            }
+        }
+    } else {
+        quote! {
+            match #source {
+                std::result::Result::Ok(#item) => std::result::Result::Ok(#ok),
+                std::result::Result::Err(#error) => std::result::Result::Err(#err),
+            }
+        }
+    }
+}
+
+pub(crate) fn box_error() -> TokenStream {
+    quote! {
+        std::boxed::Box<dyn std::error::Error + std::marker::Send + std::marker::Sync>
+    }
+}
@@ -1,45 +1,37 @@
 use std::collections::HashMap;
 
-use syn::{spanned::Spanned, Field, Ident};
+use syn::{spanned::Spanned, Attribute, Field, Ident, Member, Type};
 
-use crate::{AttrMetas, MaybeOwned};
+use crate::{unraw, AttrMetas, MaybeOwned};
 
 use super::{
-    field_arg::{ConvertFieldArg, ConvertFieldMap},
+    field_arg::ConvertFieldArg,
+    flatten::FlattenStruct,
     target::{parse_field_conversion_meta, Conversion},
 };
 
-pub(crate) struct ConvertField<'a> {
-    pub ident: &'a Ident,
+/// The `#[convert(...)]` attributes of a field or a variant.
+pub(crate) struct ConvertAttrs {
     pub all: ConvertFieldArg,
     pub target: HashMap<Conversion, ConvertFieldArg>,
 }
 
-impl<'a> ConvertField<'a> {
+impl ConvertAttrs {
     pub(crate) fn get_arg_for_conversion(
-        &'a self,
+        &self,
         conversion: &Conversion,
-    ) -> MaybeOwned<'a, ConvertFieldArg> {
+    ) -> MaybeOwned<'_, ConvertFieldArg> {
         match self.target.get(conversion) {
             Some(target_arg) => MaybeOwned::Owned(self.all.merge(target_arg)),
             None => MaybeOwned::Borrowed(&self.all),
         }
     }
 
-    pub(crate) fn from_field(field: &'a Field, self_ident: &Ident) -> syn::Result<Self> {
-        let Some(ref ident) = field.ident else {
-            return Err(syn::Error::new(
-                field.span(),
-                "expected named field.\n\
-                \n\
-                The Convert macro only supports structs with named fields like `struct Foo {{ field: Type }}`"
-            ));
-        };
-
+    pub(crate) fn from_attrs(attrs: &[Attribute], self_ident: &Ident) -> syn::Result<Self> {
         let mut all: Option<ConvertFieldArg> = None;
         let mut target_arg = HashMap::new();
 
-        for attr in &field.attrs {
+        for attr in attrs {
             if !attr.path().is_ident("convert") {
                 continue;
             }
@@ -57,28 +49,87 @@ impl<'a> ConvertField<'a> {
             match parse_field_conversion_meta(meta, self_ident)? {
                 Some(conversions) => {
                     iter.next();
-                    let arg = ConvertFieldArg::from_meta_iter(&field.ty, iter)?;
+                    let arg = ConvertFieldArg::from_meta_iter(iter)?;
+                    if arg.flatten {
+                        return Err(syn::Error::new(
+                            attr.span(),
+                            "`flatten` applies to every conversion and can not have a filter of target",
+                        ));
+                    }
                     for conversion in conversions {
                         target_arg.insert(conversion, arg.clone());
                     }
                 }
                 None => {
-                    let arg = ConvertFieldArg::from_meta_iter(&field.ty, iter)?;
+                    let arg = ConvertFieldArg::from_meta_iter(iter)?;
                     all = Some(arg);
                 }
             }
         }
 
-        let all = all.unwrap_or_else(|| ConvertFieldArg {
-            ignore: false,
-            map: ConvertFieldMap::gen_suffix(&field.ty),
-            rename: None,
-        });
+        Ok(ConvertAttrs {
+            all: all.unwrap_or_default(),
+            target: target_arg,
+        })
+    }
+}
+
+pub(crate) struct ConvertField<'a> {
+    pub member: Member,
+    pub ty: &'a Type,
+    pub attrs: ConvertAttrs,
+    /// The fields of the struct of a `flatten` field, once resolved
+    pub flatten: Option<FlattenStruct<'a>>,
+}
+
+impl<'a> ConvertField<'a> {
+    pub(crate) fn get_arg_for_conversion(
+        &self,
+        conversion: &Conversion,
+    ) -> MaybeOwned<'_, ConvertFieldArg> {
+        self.attrs.get_arg_for_conversion(conversion)
+    }
+
+    /// Whether the field makes `conversion` fallible, including the fields of a flattened struct
+    pub(crate) fn is_fallible(&self, conversion: &Conversion, is_from: bool) -> bool {
+        let arg = self.get_arg_for_conversion(conversion);
+        match &self.flatten {
+            Some(nested) if !arg.ignore => nested.is_fallible(conversion, is_from),
+            _ => arg.is_fallible(is_from),
+        }
+    }
+
+    /// The name of the field, the index for tuple fields
+    pub(crate) fn name(&self) -> String {
+        member_name(&self.member)
+    }
+
+    /// `index` is the position of the field, used for tuple fields
+    pub(crate) fn from_field(
+        field: &'a Field,
+        index: usize,
+        self_ident: &Ident,
+    ) -> syn::Result<Self> {
+        let member = match field.ident {
+            Some(ref ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(syn::Index {
+                index: index as u32,
+                span: field.ty.span(),
+            }),
+        };
 
         Ok(ConvertField {
-            ident,
-            all,
-            target: target_arg,
+            member,
+            ty: &field.ty,
+            attrs: ConvertAttrs::from_attrs(&field.attrs, self_ident)?,
+            flatten: None,
         })
     }
 }
+
+pub(crate) fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => unraw(ident),
+        Member::Unnamed(index) => index.index.to_string(),
+    }
+}
@@ -1,109 +1,506 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
-use syn::{spanned::Spanned, Expr, ExprPath, Lit, LitStr, Meta, Type};
+use syn::{
+    ext::IdentExt, parse::Parser, punctuated::Punctuated, spanned::Spanned, Expr, ExprPath, Ident,
+    Lit, LitStr, Member, Meta, Token, Type,
+};
 
-use crate::{check_duplicate, is_option, is_vec};
+use crate::check_duplicate;
+
+use super::{
+    container::{box_error, gen_conversion, LeafConversion, Shape},
+    field::member_name,
+    generate::GenerateField,
+    target::{is_target_keyword, ConvertTarget},
+};
 
 #[derive(Clone)]
 pub(crate) enum ConvertFieldMap {
     Map(Expr),
-    FieldFn(ExprPath),
+    TryMap(Expr),
+    /// A function or a closure called with the field
+    FieldFn(Expr),
+    TryFieldFn(Expr),
     StructFn(ExprPath),
-    Suffix(TokenStream),
+    /// A module with `into_target` and `from_target`
+    With(ExprPath),
+    /// A module with `try_into_target` and `try_from_target`
+    TryWith(ExprPath),
 }
 
-impl ConvertFieldMap {
-    pub(crate) fn gen_suffix(ty: &Type) -> Self {
-        let suffix = {
-            if is_vec(ty) {
-                quote::quote! {.into_iter().map(std::convert::Into::into).collect()}
-            } else if is_option(ty) {
-                quote::quote! {.map(std::convert::Into::into)}
+/// How the `Option` of the other side is handled, set by `required`, `unwrap_or_default` or `unwrap_or`
+///
+/// The content is read out of the `Option` in `from`, and wrapped in `Some` in `into`.
+#[derive(Clone)]
+pub(crate) enum ConvertFieldOptional {
+    /// `None` is an error, `more_convert::MissingFieldError`
+    Required,
+    UnwrapOrDefault,
+    UnwrapOr(Expr),
+}
+
+impl ConvertFieldOptional {
+    /// Wraps `convert`, the default conversion of the field, `name` is the field of the other side.
+    fn to_token(
+        &self,
+        source: &TokenStream,
+        name: &str,
+        fallible: bool,
+        is_from: bool,
+        convert: impl Fn(&TokenStream) -> TokenStream,
+    ) -> TokenStream {
+        if !is_from {
+            let inner = convert(source);
+            return if fallible {
+                quote! { (#inner).map(std::option::Option::Some) }
             } else {
-                quote::quote! { .into() }
+                quote! { std::option::Option::Some(#inner) }
+            };
+        }
+
+        let item = Ident::new("item", Span::mixed_site());
+        let inner = convert(&item.to_token_stream());
+        let (some, none) = match self {
+            ConvertFieldOptional::Required => {
+                let box_error = box_error();
+                (
+                    quote! { (#inner).map_err(std::convert::Into::<#box_error>::into) },
+                    quote! {
+                        std::result::Result::Err(std::convert::Into::<#box_error>::into(
+                            more_convert::MissingFieldError { field: #name },
+                        ))
+                    },
+                )
             }
+            ConvertFieldOptional::UnwrapOrDefault => {
+                (inner, quote! { std::default::Default::default() })
+            }
+            ConvertFieldOptional::UnwrapOr(expr) => (inner, expr.to_token_stream()),
+        };
+        let none = match self {
+            ConvertFieldOptional::Required if fallible => none,
+            _ if fallible => quote! { std::result::Result::Ok(#none) },
+            _ => none,
         };
-        ConvertFieldMap::Suffix(suffix)
+        quote! {
+            match #source {
+                std::option::Option::Some(#item) => #some,
+                std::option::Option::None => #none,
+            }
+        }
+    }
+}
+
+/// How the source field is accessed
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SourceAccess {
+    /// Moved out of the source
+    Owned,
+    /// Cloned from a reference to the field (`into_ref`)
+    Clone,
+    /// Converted from a reference to the field with `From<&T>` (`into_ref` and `by_ref`)
+    Ref,
+}
+
+impl ConvertFieldMap {
+    /// Whether the map returns a `Result`,
+    /// which makes the whole conversion fallible.
+    pub(crate) fn is_fallible(&self) -> bool {
+        matches!(
+            self,
+            ConvertFieldMap::TryMap(_)
+                | ConvertFieldMap::TryFieldFn(_)
+                | ConvertFieldMap::TryWith(_)
+        )
+    }
+
+    /// Whether the map reads `value` instead of the source field
+    pub(crate) fn reads_value(&self) -> bool {
+        matches!(
+            self,
+            ConvertFieldMap::Map(_) | ConvertFieldMap::TryMap(_) | ConvertFieldMap::StructFn(_)
+        )
     }
 
-    pub(crate) fn to_token(&self, ident: &TokenStream) -> TokenStream {
+    /// Generates the expression of this field, `source` is the expression of the source field.
+    ///
+    /// `access` is how the source field is read (`into_ref` unless `Owned`),
+    /// `is_from` when `Self` is the target of the conversion.
+    pub(crate) fn to_token(
+        &self,
+        source: &TokenStream,
+        access: SourceAccess,
+        is_from: bool,
+    ) -> TokenStream {
+        let by_ref = access != SourceAccess::Owned;
         match self {
-            ConvertFieldMap::Map(map) => map.to_token_stream(),
-            ConvertFieldMap::FieldFn(map) => quote! {
-                #map(value.#ident)
+            ConvertFieldMap::With(module) | ConvertFieldMap::TryWith(module) => {
+                let function = match (self.is_fallible(), is_from) {
+                    (false, false) => "into_target",
+                    (false, true) => "from_target",
+                    (true, false) => "try_into_target",
+                    (true, true) => "try_from_target",
+                };
+                let function = Ident::new(function, module.span());
+                // the functions take the field by value, like the other side of `from`
+                let source = match access {
+                    SourceAccess::Clone => quote! { std::clone::Clone::clone(#source) },
+                    _ => source.clone(),
+                };
+                quote! {
+                    #module::#function(#source)
+                }
+            }
+            ConvertFieldMap::Map(map) | ConvertFieldMap::TryMap(map) => map.to_token_stream(),
+            // the type of the parameter of a closure is inferred from the bound,
+            // not from an immediate call
+            ConvertFieldMap::FieldFn(Expr::Closure(map))
+            | ConvertFieldMap::TryFieldFn(Expr::Closure(map)) => quote! {{
+                fn __map_field<T, R>(field: T, map: impl FnOnce(T) -> R) -> R {
+                    map(field)
+                }
+                __map_field(#source, #map)
+            }},
+            ConvertFieldMap::FieldFn(map) | ConvertFieldMap::TryFieldFn(map) => quote! {
+                #map(#source)
+            },
+            ConvertFieldMap::StructFn(map) if by_ref => quote! {
+                #map(value)
             },
             ConvertFieldMap::StructFn(map) => quote! {
                 #map(&value)
             },
-            ConvertFieldMap::Suffix(suffix) => quote! {
-                value.#ident #suffix
-            },
         }
     }
 }
 
+/// A dotted path of fields of the other side, set by `source` (`from`) or `target_path` (`into`)
 #[derive(Clone)]
+pub(crate) struct MemberPath {
+    /// `source`, or else `target_path`
+    pub is_source: bool,
+    pub span: Span,
+    pub first: Member,
+    pub rest: Vec<Member>,
+}
+
+impl MemberPath {
+    fn parse(lit: &LitStr, is_source: bool) -> syn::Result<Self> {
+        let mut members = lit
+            .value()
+            .split('.')
+            .map(|part| {
+                if !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()) {
+                    return Ok(Member::Unnamed(syn::Index {
+                        index: part.parse().map_err(|_| {
+                            syn::Error::new(lit.span(), format!("invalid index `{}`", part))
+                        })?,
+                        span: lit.span(),
+                    }));
+                }
+                let mut ident = Ident::parse_any.parse_str(part).map_err(|_| {
+                    syn::Error::new(lit.span(), format!("invalid field `{}`", part))
+                })?;
+                ident.set_span(lit.span());
+                Ok(Member::Named(ident))
+            })
+            .collect::<syn::Result<Vec<_>>>()?
+            .into_iter();
+        let Some(first) = members.next() else {
+            return Err(syn::Error::new(lit.span(), "expected a path"));
+        };
+
+        Ok(Self {
+            is_source,
+            span: lit.span(),
+            first,
+            rest: members.collect(),
+        })
+    }
+
+    /// Checks that the path is on the other side of the conversion,
+    /// `source` for `from` and `target_path` for `into`
+    pub(crate) fn check(&self, is_from: bool) -> syn::Result<()> {
+        match (self.is_source, is_from) {
+            (true, false) => Err(syn::Error::new(
+                self.span,
+                "`source` is only supported in `from` conversions, use `target_path` for `into`",
+            )),
+            (false, true) => Err(syn::Error::new(
+                self.span,
+                "`target_path` is only supported in `into` conversions, use `source` for `from`",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// The dotted path, e.g. `meta.created_at`
+    pub(crate) fn name(&self) -> String {
+        std::iter::once(&self.first)
+            .chain(&self.rest)
+            .map(member_name)
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+}
+
+/// The arguments of a field, or of a variant for enums.
+#[derive(Clone, Default)]
 pub(crate) struct ConvertFieldArg {
     pub ignore: bool,
-    pub map: ConvertFieldMap,
-    pub rename: Option<LitStr>,
+    /// `None` is the default conversion, see [`gen_conversion`]
+    pub map: Option<ConvertFieldMap>,
+    /// The shape of the type of the field for the default conversion, set by `container`
+    pub container: Option<Type>,
+    /// The member of the other side, set by `rename`, `name` or `index`
+    pub rename: Option<Member>,
+    /// The nested member of the other side, set by `source` or `target_path`
+    pub path: Option<MemberPath>,
+    /// Convert the field with `From<&T>` instead of `Clone` in `into_ref`
+    pub by_ref: bool,
+    /// Spread the fields of this struct field into the other side, or gather them back
+    pub flatten: bool,
+    /// The other side is an `Option` of the field
+    pub optional: Option<ConvertFieldOptional>,
+    /// The conversion of the leaves of the default conversion instead of `Into`
+    pub leaf: Option<LeafConversion>,
+    /// The fields of the target computed from this field instead of converting it (`into` only)
+    pub split: Option<Vec<GenerateField>>,
 }
 
 impl ConvertFieldArg {
     pub(crate) fn merge(&self, superiority: &Self) -> Self {
+        // `rename` and the paths replace each other
+        let renamed = if superiority.rename.is_some() || superiority.path.is_some() {
+            superiority
+        } else {
+            self
+        };
         Self {
             ignore: self.ignore || superiority.ignore,
-            map: {
-                match &superiority.map {
-                    ConvertFieldMap::Suffix(_) => self.map.clone(),
-                    _ => superiority.map.clone(),
-                }
-            },
-            rename: match &superiority.rename {
-                Some(rename) => Some(rename.clone()),
-                None => self.rename.clone(),
-            },
+            map: superiority.map.clone().or_else(|| self.map.clone()),
+            container: superiority
+                .container
+                .clone()
+                .or_else(|| self.container.clone()),
+            rename: renamed.rename.clone(),
+            path: renamed.path.clone(),
+            by_ref: self.by_ref || superiority.by_ref,
+            flatten: self.flatten || superiority.flatten,
+            optional: superiority
+                .optional
+                .clone()
+                .or_else(|| self.optional.clone()),
+            leaf: superiority.leaf.or(self.leaf),
+            split: superiority.split.clone().or_else(|| self.split.clone()),
         }
     }
+
+    /// Whether the field makes the conversion fallible, `is_from` when `Self` is the target
+    pub(crate) fn is_fallible(&self, is_from: bool) -> bool {
+        if self.ignore || self.split.is_some() {
+            return false;
+        }
+        match &self.map {
+            Some(map) => map.is_fallible(),
+            None => {
+                self.leaf.is_some_and(LeafConversion::is_fallible)
+                    || is_from && matches!(self.optional, Some(ConvertFieldOptional::Required))
+            }
+        }
+    }
+
+    /// How the source field is accessed in a conversion, `by_ref` for `into_ref`
+    pub(crate) fn access(&self, by_ref: bool) -> SourceAccess {
+        match (by_ref, self.by_ref) {
+            (false, _) => SourceAccess::Owned,
+            // the leaf conversions only borrow the field
+            (true, false) if self.leaf.is_some() => SourceAccess::Ref,
+            (true, false) => SourceAccess::Clone,
+            (true, true) => SourceAccess::Ref,
+        }
+    }
+
+    /// The shape of the field of type `ty`, or of the `container` hint
+    pub(crate) fn shape<'a>(&'a self, ty: &'a Type) -> Shape<'a> {
+        match &self.container {
+            Some(container) => Shape::hinted(container),
+            None => Shape::new(ty),
+        }
+    }
+
+    /// Generates the expression of this field.
+    ///
+    /// In `into_ref`, `source` is a reference to the field.
+    /// `is_from` is set when `Self` is the target of the conversion.
+    /// `name` is the field of the source, reported by `required`.
+    /// Returns the expression and whether it evaluates to a `Result`.
+    pub(crate) fn to_token(
+        &self,
+        ty: &Type,
+        source: &TokenStream,
+        name: &str,
+        fallible: bool,
+        target: &ConvertTarget,
+        is_from: bool,
+    ) -> (TokenStream, bool) {
+        let by_ref = target.conversion.by_ref;
+        let shape = self.shape(ty);
+        let convert = |source: &TokenStream| {
+            gen_conversion(
+                &shape,
+                source,
+                fallible,
+                self.access(by_ref),
+                self.leaf,
+                target.options.context.is_some(),
+            )
+        };
+        match (&self.map, &self.optional) {
+            (Some(map), _) => (
+                map.to_token(source, self.access(by_ref), is_from),
+                map.is_fallible(),
+            ),
+            (None, Some(optional)) => (
+                optional.to_token(source, name, fallible, is_from, convert),
+                fallible,
+            ),
+            (None, None) => (convert(source), fallible),
+        }
+    }
+}
+
+/// The value of `map_field` and `try_map_field`, a path of a function or a closure
+fn field_fn(value: Expr) -> syn::Result<Expr> {
+    match value {
+        Expr::Path(_) | Expr::Closure(_) => Ok(value),
+        _ => Err(syn::Error::new(
+            value.span(),
+            "expected a path or a closure",
+        )),
+    }
 }
 
 const NOT_FIRST: &str = "target attribute must be first";
 
 impl ConvertFieldArg {
-    pub(crate) fn from_meta_iter(
-        ty: &Type,
-        meta_iter: impl IntoIterator<Item = Meta>,
-    ) -> syn::Result<Self> {
+    pub(crate) fn from_meta_iter(meta_iter: impl IntoIterator<Item = Meta>) -> syn::Result<Self> {
         let mut ignore = false;
         let mut map = None;
+        let mut container = None;
         let mut rename = None;
+        let mut path = None;
+        let mut by_ref = false;
+        let mut flatten = false;
+        let mut optional = None;
+        let mut leaf = None;
+        let mut split = None;
+        // the span of the options of the default conversion, which conflict with maps
+        let mut default_span = None;
+
+        macro_rules! check_duplicate_rename {
+            ($span:expr) => {
+                check_duplicate!(
+                    @__message $span,
+                    rename,
+                    rename.is_some() || path.is_some(),
+                    "chose one of `rename`, `name`, `index`, `source` or `target_path`"
+                );
+            };
+        }
 
         macro_rules! check_duplicate_map {
             ($span:expr) => {
                 check_duplicate!(
                     $span,
                     map,
-                    "chose one of `map`, `map_field` or `map_struct`"
+                    "chose one of `map`, `map_field`, `map_struct`, `try_map`, `try_map_field`, `with` or `try_with`"
+                );
+            };
+        }
+
+        macro_rules! check_duplicate_optional {
+            ($span:expr) => {
+                check_duplicate!(
+                    $span,
+                    optional,
+                    "chose one of `required`, `unwrap_or_default` or `unwrap_or`"
                 );
             };
         }
 
         for meta in meta_iter {
             match meta {
+                Meta::Path(path)
+                    if path
+                        .get_ident()
+                        .and_then(LeafConversion::from_ident)
+                        .is_some() =>
+                {
+                    check_duplicate!(
+                        path.span(),
+                        leaf,
+                        "chose one of `parse`, `display`, `cast`, `checked`, `saturating` or `round`"
+                    );
+                    leaf = path.get_ident().and_then(LeafConversion::from_ident);
+                    default_span = Some(path.span());
+                }
+                Meta::Path(path) if path.is_ident("required") => {
+                    check_duplicate_optional!(path.span());
+                    optional = Some(ConvertFieldOptional::Required);
+                    default_span = Some(path.span());
+                }
+                Meta::Path(path) if path.is_ident("unwrap_or_default") => {
+                    check_duplicate_optional!(path.span());
+                    optional = Some(ConvertFieldOptional::UnwrapOrDefault);
+                    default_span = Some(path.span());
+                }
+                Meta::NameValue(meta) if meta.path.is_ident("unwrap_or") => {
+                    check_duplicate_optional!(meta.path.span());
+                    default_span = Some(meta.path.span());
+                    optional = Some(ConvertFieldOptional::UnwrapOr(meta.value));
+                }
                 Meta::Path(path) if path.is_ident("ignore") => {
                     check_duplicate!(path.span(), ignore, ignore);
                     ignore = true;
                 }
+                Meta::Path(path) if path.is_ident("by_ref") => {
+                    check_duplicate!(path.span(), by_ref, by_ref);
+                    by_ref = true;
+                }
+                Meta::Path(path) if path.is_ident("flatten") => {
+                    check_duplicate!(path.span(), flatten, flatten);
+                    flatten = true;
+                }
                 Meta::NameValue(meta) if meta.path.is_ident("map") => {
                     check_duplicate_map!(meta.path.span());
                     map = Some(ConvertFieldMap::Map(meta.value));
                 }
                 Meta::NameValue(meta) if meta.path.is_ident("map_field") => {
+                    check_duplicate_map!(meta.path.span());
+                    map = Some(ConvertFieldMap::FieldFn(field_fn(meta.value)?));
+                }
+                Meta::NameValue(meta) if meta.path.is_ident("try_map") => {
+                    check_duplicate_map!(meta.path.span());
+                    map = Some(ConvertFieldMap::TryMap(meta.value));
+                }
+                Meta::NameValue(meta) if meta.path.is_ident("try_map_field") => {
+                    check_duplicate_map!(meta.path.span());
+                    map = Some(ConvertFieldMap::TryFieldFn(field_fn(meta.value)?));
+                }
+                Meta::NameValue(meta) if meta.path.is_ident("with") => {
+                    check_duplicate_map!(meta.path.span());
+                    let Expr::Path(path) = meta.value else {
+                        return Err(syn::Error::new(meta.value.span(), "expected path"));
+                    };
+                    map = Some(ConvertFieldMap::With(path));
+                }
+                Meta::NameValue(meta) if meta.path.is_ident("try_with") => {
                     check_duplicate_map!(meta.path.span());
                     let Expr::Path(path) = meta.value else {
                         return Err(syn::Error::new(meta.value.span(), "expected path"));
                     };
-                    map = Some(ConvertFieldMap::FieldFn(path));
+                    map = Some(ConvertFieldMap::TryWith(path));
                 }
                 Meta::NameValue(meta) if meta.path.is_ident("map_struct") => {
                     check_duplicate_map!(meta.path.span());
@@ -112,8 +509,10 @@ impl ConvertFieldArg {
                     };
                     map = Some(ConvertFieldMap::StructFn(path));
                 }
-                Meta::NameValue(meta) if meta.path.is_ident("rename") => {
-                    check_duplicate!(meta.path.span(), rename);
+                Meta::NameValue(meta)
+                    if meta.path.is_ident("rename") || meta.path.is_ident("name") =>
+                {
+                    check_duplicate_rename!(meta.path.span());
                     let Expr::Lit(lit) = meta.value else {
                         return Err(syn::Error::new(meta.value.span(), "expected literal"));
                     };
@@ -122,15 +521,57 @@ impl ConvertFieldArg {
                         return Err(syn::Error::new_spanned(lit, "expected string literal"));
                     };
 
-                    rename = Some(lit_str);
+                    rename = Some(Member::Named(lit_str.parse_with(Ident::parse_any)?));
                 }
-                Meta::List(list) if list.path.is_ident("from") => {
-                    return Err(syn::Error::new(list.span(), NOT_FIRST))
+                Meta::NameValue(meta)
+                    if meta.path.is_ident("source") || meta.path.is_ident("target_path") =>
+                {
+                    check_duplicate_rename!(meta.path.span());
+                    let Expr::Lit(lit) = meta.value else {
+                        return Err(syn::Error::new(meta.value.span(), "expected literal"));
+                    };
+
+                    let Lit::Str(lit_str) = lit.lit else {
+                        return Err(syn::Error::new_spanned(lit, "expected string literal"));
+                    };
+
+                    path = Some(MemberPath::parse(&lit_str, meta.path.is_ident("source"))?);
                 }
-                Meta::List(list) if list.path.is_ident("into") => {
-                    return Err(syn::Error::new(list.span(), NOT_FIRST))
+                Meta::NameValue(meta) if meta.path.is_ident("container") => {
+                    check_duplicate!(meta.path.span(), container);
+                    let Expr::Lit(lit) = meta.value else {
+                        return Err(syn::Error::new(meta.value.span(), "expected literal"));
+                    };
+
+                    let Lit::Str(lit_str) = lit.lit else {
+                        return Err(syn::Error::new_spanned(lit, "expected string literal"));
+                    };
+
+                    container = Some(lit_str.parse()?);
                 }
-                Meta::List(list) if list.path.is_ident("from_into") => {
+                Meta::NameValue(meta) if meta.path.is_ident("index") => {
+                    check_duplicate_rename!(meta.path.span());
+                    let Expr::Lit(lit) = meta.value else {
+                        return Err(syn::Error::new(meta.value.span(), "expected literal"));
+                    };
+
+                    let Lit::Int(lit_int) = lit.lit else {
+                        return Err(syn::Error::new_spanned(lit, "expected integer literal"));
+                    };
+
+                    rename = Some(Member::Unnamed(syn::Index {
+                        index: lit_int.base10_parse()?,
+                        span: lit_int.span(),
+                    }));
+                }
+                Meta::List(list) if list.path.is_ident("split") => {
+                    check_duplicate!(list.path.span(), split);
+                    let fields = list.parse_args_with(
+                        Punctuated::<GenerateField, Token![,]>::parse_terminated,
+                    )?;
+                    split = Some((list.path.span(), fields.into_iter().collect()));
+                }
+                Meta::List(list) if is_target_keyword(&list.path) => {
                     return Err(syn::Error::new(list.span(), NOT_FIRST))
                 }
                 _ => {
@@ -142,10 +583,41 @@ impl ConvertFieldArg {
             }
         }
 
+        if let (Some(_), Some(span)) = (&map, default_span) {
+            return Err(syn::Error::new(
+                span,
+                "the options of the default conversion (e.g. `required`, `parse` or `cast`) \
+                can not be combined with maps",
+            ));
+        }
+
+        if let Some((span, _)) = &split {
+            if map.is_some()
+                || rename.is_some()
+                || path.is_some()
+                || container.is_some()
+                || by_ref
+                || flatten
+                || default_span.is_some()
+            {
+                return Err(syn::Error::new(
+                    *span,
+                    "`split` can not be combined with maps, renames or the options of the default conversion",
+                ));
+            }
+        }
+
         Ok(Self {
             ignore,
-            map: map.unwrap_or_else(|| ConvertFieldMap::gen_suffix(ty)),
+            map,
+            container,
             rename,
+            path,
+            by_ref,
+            flatten,
+            optional,
+            leaf,
+            split: split.map(|(_, fields)| fields),
         })
     }
 }
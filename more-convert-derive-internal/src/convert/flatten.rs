@@ -0,0 +1,430 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{braced, parse::Parse, spanned::Spanned, DeriveInput, Ident, Member, Token, Type};
+
+use crate::{require_struct, unraw};
+
+use super::{
+    bind_source,
+    body::{ErrorMode, FieldToken, FieldValue},
+    field::{member_name, ConvertField},
+    field_arg::{ConvertFieldArg, ConvertFieldMap},
+    renamed_member,
+    target::{Conversion, ConvertTarget, ConvertType},
+};
+
+/// The definition of a struct deriving `Convert` with its `flatten` fields resolved,
+/// written as `{ struct .. } { field => { struct .. } { .. }, .. }`.
+///
+/// The derive of a struct can't see the fields of another struct,
+/// so every struct defines a flatten macro forwarding its definition,
+/// and a struct with `flatten` fields calls the macro of each of them in turn,
+/// passing what is resolved so far. The last macro calls `more_convert::__convert_flatten`,
+/// which continues the derive with every definition.
+pub(crate) struct FlattenDef {
+    pub input: DeriveInput,
+    pub resolved: Vec<(Ident, FlattenDef)>,
+}
+
+impl Parse for FlattenDef {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let content;
+        braced!(content in input);
+        let derive_input = content.parse()?;
+
+        let content;
+        braced!(content in input);
+        let mut resolved = Vec::new();
+        while !content.is_empty() {
+            let field = content.parse()?;
+            content.parse::<Token![=>]>()?;
+            resolved.push((field, content.parse()?));
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<Token![,]>()?;
+        }
+
+        Ok(Self {
+            input: derive_input,
+            resolved,
+        })
+    }
+}
+
+impl ToTokens for FlattenDef {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(gen_def(&self.input, &self.resolved));
+    }
+}
+
+fn gen_def(input: &DeriveInput, resolved: &[(Ident, FlattenDef)]) -> TokenStream {
+    let resolved = resolved
+        .iter()
+        .map(|(field, def)| quote! { #field => #def });
+    quote! {
+        { #input } { #( #resolved, )* }
+    }
+}
+
+/// A struct whose fields are spread into the other side of the conversion of its parent
+pub(crate) struct FlattenStruct<'a> {
+    pub ident: &'a Ident,
+    pub fields: Vec<ConvertField<'a>>,
+}
+
+impl<'a> FlattenStruct<'a> {
+    fn from_def(def: &'a FlattenDef, span: Span) -> syn::Result<Self> {
+        let input = &def.input;
+        let syn::Fields::Named(fields) = require_struct(input)? else {
+            return Err(syn::Error::new(
+                span,
+                "`flatten` requires a struct with named fields",
+            ));
+        };
+        if !input.generics.params.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                "`flatten` does not support generic structs",
+            ));
+        }
+
+        let mut fields = fields
+            .named
+            .iter()
+            .enumerate()
+            .map(|(i, f)| ConvertField::from_field(f, i, &input.ident))
+            .collect::<syn::Result<Vec<_>>>()?;
+        if let Some((field, _)) = resolve_fields(&mut fields, &def.resolved)? {
+            return Err(syn::Error::new(
+                field.span(),
+                format!("`{}` of `{}` is not resolved", unraw(&field), input.ident),
+            ));
+        }
+
+        Ok(Self {
+            ident: &input.ident,
+            fields,
+        })
+    }
+
+    /// Whether a field of this struct makes `conversion` of the parent fallible
+    pub(crate) fn is_fallible(&self, conversion: &Conversion, is_from: bool) -> bool {
+        let conversion = nested_conversion(self.ident, conversion, is_from);
+        self.fields
+            .iter()
+            .any(|field| field.is_fallible(&conversion, is_from))
+    }
+}
+
+/// Sets the struct of every `flatten` field from `resolved`.
+///
+/// Returns the first field that is not resolved yet, with its type.
+pub(crate) fn resolve_fields<'a>(
+    fields: &mut [ConvertField<'a>],
+    resolved: &'a [(Ident, FlattenDef)],
+) -> syn::Result<Option<(Ident, &'a Type)>> {
+    for field in fields {
+        if !field.attrs.all.flatten {
+            continue;
+        }
+        let Member::Named(ident) = &field.member else {
+            return Err(syn::Error::new(
+                field.member.span(),
+                "`flatten` is not supported on tuple fields",
+            ));
+        };
+        type_path(field.ty)?;
+
+        match resolved.iter().find(|(resolved, _)| resolved == ident) {
+            Some((_, def)) => field.flatten = Some(FlattenStruct::from_def(def, field.ty.span())?),
+            None => return Ok(Some((ident.clone(), field.ty))),
+        }
+    }
+    Ok(None)
+}
+
+/// Calls the flatten macro of the struct of `field`,
+/// which appends its definition to `resolved` and continues the derive.
+///
+/// The macro is named after the struct, so it is found through the path of the type,
+/// including a `use` of the struct.
+pub(crate) fn gen_resolve(
+    input: &DeriveInput,
+    resolved: &[(Ident, FlattenDef)],
+    field: &Ident,
+    ty: &Type,
+) -> syn::Result<TokenStream> {
+    let path = type_path(ty)?;
+    let def = gen_def(input, resolved);
+    Ok(quote! {
+        #path! { #def #field }
+    })
+}
+
+/// Generates the flatten macro of the deriving struct, see [`FlattenDef`].
+///
+/// The `macro_rules` gets a unique name and is imported under the name of the struct,
+/// because a macro-expanded `macro_rules` conflicts with another one of the same name
+/// in a parent module (e.g. the macro of a struct of the same name).
+/// The name is a hash of the definition, so it doesn't depend on the order of expansion.
+///
+/// The macro is `pub(crate)`, so only the structs of the same crate can be flattened.
+pub(crate) fn gen_flatten_macro(
+    input: &DeriveInput,
+    resolved: &[(Ident, FlattenDef)],
+) -> TokenStream {
+    let name = &input.ident;
+    let def = gen_def(input, resolved);
+    let mut hasher = DefaultHasher::new();
+    def.to_string().hash(&mut hasher);
+    let unique = format_ident!("__more_convert_flatten_{}_{:016x}", name, hasher.finish());
+    quote! {
+        #[doc(hidden)]
+        #[allow(unused_macros)]
+        macro_rules! #unique {
+            ({ $($input:tt)* } { $($resolved:tt)* } $field:ident) => {
+                more_convert::__convert_flatten! {
+                    { $($input)* } { $($resolved)* $field => #def, }
+                }
+            };
+        }
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use #unique as #name;
+    }
+}
+
+/// Generates `more_convert::__Flatten` for the `flatten` fields of the deriving struct,
+/// naming the type of each of them by its index, e.g. `<User as __Flatten<1>>::Field`.
+///
+/// The derive of a parent constructs the nested structs through the trait,
+/// as their types may not be in scope there.
+pub(crate) fn gen_flatten_impls(input: &DeriveInput, fields: &[ConvertField]) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut impls = TokenStream::new();
+    for (index, field) in fields.iter().enumerate() {
+        if field.flatten.is_none() {
+            continue;
+        }
+        let index = Literal::usize_unsuffixed(index);
+        let ty = field.ty;
+        impls.extend(quote! {
+            #[automatically_derived]
+            impl #impl_generics more_convert::__Flatten<#index> for #ident #ty_generics #where_clause {
+                type Field = #ty;
+            }
+        });
+    }
+    impls
+}
+
+/// Generates the fields of the target from the fields of the flattened struct at `source`,
+/// which is a reference in `into_ref`. `path` is the path of `source` used by conversion errors.
+pub(crate) fn spread_fields(
+    nested: &FlattenStruct,
+    target: &ConvertTarget,
+    source: &TokenStream,
+    path: &str,
+    mode: ErrorMode,
+    field_tokens: &mut Vec<FieldToken>,
+) -> syn::Result<()> {
+    let conversion = nested_conversion(nested.ident, &target.conversion, false);
+    for field in &nested.fields {
+        let arg = field.get_arg_for_conversion(&conversion);
+        if arg.ignore {
+            continue;
+        }
+
+        let member = &field.member;
+        let path = format!("{}.{}", path, field.name());
+        if let Some(inner) = &field.flatten {
+            check_flatten_arg(field, &arg)?;
+            let source = quote! { #source.#member };
+            spread_fields(inner, target, &source, &path, mode, field_tokens)?;
+            continue;
+        }
+        check_nested_arg(field, &arg)?;
+
+        let source = if conversion.by_ref {
+            quote! { &#source.#member }
+        } else {
+            quote! { #source.#member }
+        };
+        let (token, is_result) =
+            arg.to_token(field.ty, &source, &path, mode.is_fallible(), target, false);
+        let target_member =
+            renamed_member(member, arg.rename.as_ref(), &target.options.rename_rule)?;
+        field_tokens.push(FieldToken::converted(
+            target_member.to_token_stream(),
+            path,
+            token,
+            is_result,
+        ));
+    }
+    Ok(())
+}
+
+/// Generates the flattened struct of the field of type `ty` from the fields of the source.
+///
+/// The fields of the source are destructured with `bindings`,
+/// and the ignored fields of the struct are filled with `Default::default()`.
+pub(crate) fn gather_fields(
+    nested: &FlattenStruct,
+    ty: &Type,
+    target: &ConvertTarget,
+    mode: ErrorMode,
+    bindings: &mut Vec<(Member, Ident)>,
+) -> syn::Result<FieldValue> {
+    gather_nested(
+        nested,
+        &type_path(ty)?.to_token_stream(),
+        target,
+        mode,
+        bindings,
+    )
+}
+
+/// `ty` is the type of the struct, a projection of `__Flatten` for the nested ones
+fn gather_nested(
+    nested: &FlattenStruct,
+    ty: &TokenStream,
+    target: &ConvertTarget,
+    mode: ErrorMode,
+    bindings: &mut Vec<(Member, Ident)>,
+) -> syn::Result<FieldValue> {
+    let conversion = nested_conversion(nested.ident, &target.conversion, true);
+    let mut fields = Vec::with_capacity(nested.fields.len());
+    for (index, field) in nested.fields.iter().enumerate() {
+        let arg = field.get_arg_for_conversion(&conversion);
+        let member = &field.member;
+        if arg.ignore {
+            fields.push(FieldToken::converted(
+                member.to_token_stream(),
+                field.name(),
+                quote! { std::default::Default::default() },
+                false,
+            ));
+            continue;
+        }
+
+        if let Some(inner) = &field.flatten {
+            check_flatten_arg(field, &arg)?;
+            let index = Literal::usize_unsuffixed(index);
+            let ty = quote! { <#ty as more_convert::__Flatten<#index>>::Field };
+            fields.push(FieldToken {
+                target: member.to_token_stream(),
+                value: gather_nested(inner, &ty, target, mode, bindings)?,
+                assigned: false,
+            });
+            continue;
+        }
+        check_nested_arg(field, &arg)?;
+
+        let source = renamed_member(member, arg.rename.as_ref(), &target.options.rename_rule)?;
+        let local = bind_source(bindings, &source);
+        let name = member_name(&source);
+        let (token, is_result) = arg.to_token(
+            field.ty,
+            &local.to_token_stream(),
+            &name,
+            mode.is_fallible(),
+            target,
+            true,
+        );
+        fields.push(FieldToken::converted(
+            member.to_token_stream(),
+            name,
+            token,
+            is_result,
+        ));
+    }
+
+    Ok(FieldValue::Nested {
+        ty: ty.clone(),
+        fields,
+    })
+}
+
+/// Checks the arguments of a `flatten` field for a conversion
+pub(crate) fn check_flatten_arg(field: &ConvertField, arg: &ConvertFieldArg) -> syn::Result<()> {
+    if arg.map.is_some()
+        || arg.rename.is_some()
+        || arg.path.is_some()
+        || arg.by_ref
+        || arg.optional.is_some()
+        || arg.leaf.is_some()
+        || arg.split.is_some()
+    {
+        return Err(syn::Error::new(
+            field.member.span(),
+            "`flatten` can not be combined with maps, splits, renames or the options of the default conversion",
+        ));
+    }
+    Ok(())
+}
+
+/// The maps reading `value` would read the parent instead of the flattened struct,
+/// and the paths would be relative to the parent
+fn check_nested_arg(field: &ConvertField, arg: &ConvertFieldArg) -> syn::Result<()> {
+    if arg.map.as_ref().is_some_and(ConvertFieldMap::reads_value) {
+        return Err(syn::Error::new(
+            field.member.span(),
+            "`map`, `try_map` and `map_struct` are not supported in flattened structs, \
+            use `map_field`, `try_map_field` or `with`",
+        ));
+    }
+    if arg.split.is_some() {
+        return Err(syn::Error::new(
+            field.member.span(),
+            "`split` is not supported in flattened structs",
+        ));
+    }
+    if let Some(path) = &arg.path {
+        return Err(syn::Error::new(
+            path.span,
+            "`source` and `target_path` are not supported in flattened structs",
+        ));
+    }
+    Ok(())
+}
+
+/// The conversion between the flattened struct and the other side of the conversion of the parent,
+/// used to find the filtered attributes of the fields of the flattened struct
+fn nested_conversion(ident: &Ident, conversion: &Conversion, is_from: bool) -> Conversion {
+    let (from, to) = if is_from {
+        (conversion.from.clone(), ConvertType::from(ident))
+    } else {
+        (ConvertType::from(ident), conversion.to.clone())
+    };
+    Conversion {
+        from,
+        to,
+        by_ref: conversion.by_ref,
+    }
+}
+
+/// The path of the type of a `flatten` field
+fn type_path(ty: &Type) -> syn::Result<&syn::Path> {
+    match ty {
+        Type::Path(ty) if ty.qself.is_none() => {
+            if ty.path.segments.iter().any(|seg| !seg.arguments.is_empty()) {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    "`flatten` does not support generic structs",
+                ));
+            }
+            Ok(&ty.path)
+        }
+        _ => Err(syn::Error::new_spanned(
+            ty,
+            "`flatten` requires the path of a struct deriving `Convert`",
+        )),
+    }
+}
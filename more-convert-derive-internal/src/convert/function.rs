@@ -0,0 +1,225 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    braced, parenthesized, parse::Parse, spanned::Spanned, Attribute, Expr, Field, Ident, LitStr,
+    Meta, Token, Visibility,
+};
+
+use super::{
+    field::ConvertField,
+    gen_struct_body,
+    generics::ConvertGenerics,
+    target::{Conversion, ConvertOptions, ConvertTarget, ConvertType},
+};
+
+/// The input of `convert!`, e.g. `proto::User => api::User { id, name: rename(full_name) }`,
+/// optionally named as a function, e.g. `pub fn to_api: proto::User => api::User { .. }`.
+///
+/// The fields are those of the target, with the same attributes as the fields of a struct
+/// deriving `Convert` with `from(proto::User)`. A field without a type is converted as a whole.
+struct ConvertFnDef {
+    function: Option<(Visibility, Ident)>,
+    source: ConvertType,
+    target: ConvertType,
+    fields: Vec<Field>,
+}
+
+/// The options written after the name of a field, e.g. `name: rename(full_name)`,
+/// which are the options of `#[convert(..)]` taking a value
+const SHORTHANDS: &[&str] = &[
+    "rename",
+    "source",
+    "map",
+    "map_field",
+    "map_struct",
+    "try_map",
+    "try_map_field",
+    "with",
+    "try_with",
+    "unwrap_or",
+];
+
+/// Parses `option(value)` of a field into the meta `option = value` of `#[convert(..)]`,
+/// or returns `None` for a type.
+///
+/// `map(|field| ..)` and `try_map(|field| ..)` become `map_field` and `try_map_field`.
+fn parse_shorthand(input: syn::parse::ParseStream) -> syn::Result<Option<TokenStream>> {
+    let fork = input.fork();
+    let is_shorthand = fork
+        .parse::<Ident>()
+        .is_ok_and(|ident| SHORTHANDS.iter().any(|option| ident == option))
+        && fork.peek(syn::token::Paren);
+    if !is_shorthand {
+        return Ok(None);
+    }
+
+    let keyword: Ident = input.parse()?;
+    let content;
+    parenthesized!(content in input);
+    let value: TokenStream = content.parse()?;
+    // `rename` and `source` take names, which are string literals in the attribute
+    let value = if keyword == "rename" || keyword == "source" {
+        let name = value
+            .into_iter()
+            .map(|tt| match tt {
+                TokenTree::Literal(lit) => syn::parse2::<LitStr>(lit.to_token_stream())
+                    .map_or_else(|_| lit.to_string(), |lit| lit.value()),
+                tt => tt.to_string(),
+            })
+            .collect::<String>();
+        LitStr::new(&name, keyword.span()).into_token_stream()
+    } else {
+        value
+    };
+    // a closure of `map` is called with the source field, like `map_field`
+    let keyword = match syn::parse2::<Expr>(value.clone()) {
+        Ok(Expr::Closure(_)) if keyword == "map" || keyword == "try_map" => {
+            format_ident!("{}_field", keyword)
+        }
+        _ => keyword,
+    };
+    Ok(Some(quote! { #keyword = #value }))
+}
+
+/// Appends `meta` to the last `#[convert(..)]` of the field, because a second attribute
+/// without a filter of target would replace the first one
+fn push_meta(attrs: &mut Vec<Attribute>, meta: TokenStream) {
+    if let Some(Meta::List(list)) = attrs
+        .iter_mut()
+        .rev()
+        .find(|attr| attr.path().is_ident("convert"))
+        .map(|attr| &mut attr.meta)
+    {
+        let trailing = matches!(
+            list.tokens.clone().into_iter().last(),
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ','
+        );
+        if !trailing {
+            list.tokens.extend(quote! { , });
+        }
+        list.tokens.extend(meta);
+        return;
+    }
+    attrs.push(syn::parse_quote! { #[convert(#meta)] });
+}
+
+impl Parse for ConvertFnDef {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let vis: Visibility = input.parse()?;
+        let function = if input.peek(Token![fn]) || !matches!(vis, Visibility::Inherited) {
+            input.parse::<Token![fn]>()?;
+            let name = input.parse()?;
+            input.parse::<Token![:]>()?;
+            Some((vis, name))
+        } else {
+            None
+        };
+        let source = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let target = input.parse()?;
+
+        let content;
+        braced!(content in input);
+        let mut fields = Vec::new();
+        while !content.is_empty() {
+            let mut attrs = content.call(Attribute::parse_outer)?;
+            let ident = content.parse()?;
+            let mut colon_token = None;
+            let mut ty = syn::parse_quote!(_);
+            if content.peek(Token![:]) {
+                let colon = content.parse()?;
+                match parse_shorthand(&content)? {
+                    Some(meta) => push_meta(&mut attrs, meta),
+                    None => {
+                        colon_token = Some(colon);
+                        ty = content.parse()?;
+                    }
+                }
+            }
+            fields.push(Field {
+                attrs,
+                vis: syn::Visibility::Inherited,
+                mutability: syn::FieldMutability::None,
+                ident: Some(ident),
+                colon_token,
+                ty,
+            });
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<Token![,]>()?;
+        }
+
+        Ok(Self {
+            function,
+            source,
+            target,
+            fields,
+        })
+    }
+}
+
+/// Generates a closure converting the source into the target of `convert!`,
+/// or a function for `fn name: Source => Target { .. }`.
+///
+/// Neither type has to be defined in the crate, as no trait is implemented.
+/// It returns `Result<Target, more_convert::ConvertError>` if a field is fallible.
+pub fn convert_fn(input: TokenStream) -> syn::Result<TokenStream> {
+    let def: ConvertFnDef = syn::parse2(input)?;
+    // the filters of targets are rejected, so `Self` is never compared with the types
+    let self_ident = Ident::new("Self", Span::call_site());
+
+    let fields = def
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| ConvertField::from_field(f, i, &self_ident))
+        .collect::<syn::Result<Vec<_>>>()?;
+    for field in &fields {
+        if !field.attrs.target.is_empty() {
+            return Err(syn::Error::new(
+                field.member.span(),
+                "the filters of targets are not supported in `convert!`",
+            ));
+        }
+        if field.attrs.all.flatten {
+            return Err(syn::Error::new(
+                field.member.span(),
+                "`flatten` is not supported in `convert!`",
+            ));
+        }
+    }
+
+    let target = ConvertTarget {
+        conversion: Conversion {
+            from: def.source,
+            to: def.target,
+            by_ref: false,
+        },
+        fallible: false,
+        options: ConvertOptions::default(),
+    };
+    let mut generics = ConvertGenerics::new(&syn::Generics::default(), &self_ident, &target);
+    let ctor = target.conversion.to.to_pattern_path();
+    let (mode, body) = gen_struct_body(&target, &fields, &[], true, &ctor, &mut generics)?;
+
+    let Conversion { from, to, .. } = &target.conversion;
+    let output = match mode.error_type() {
+        Some(error) => quote! { std::result::Result<#to, #error> },
+        None => quote! { #to },
+    };
+    // `value` is the parameter read by `map` and `map_struct`
+    let value = Ident::new("value", Span::call_site());
+    Ok(match &def.function {
+        Some((vis, name)) => quote! {
+            #vis fn #name(#value: #from) -> #output {
+                #body
+            }
+        },
+        None => quote! {
+            |#value: #from| -> #output {
+                #body
+            }
+        },
+    })
+}
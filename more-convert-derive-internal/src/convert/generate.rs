@@ -1,23 +1,81 @@
-use syn::{parenthesized, parse::Parse};
+use syn::{ext::IdentExt, parenthesized, parse::Parse, Ident, Token};
 
+use super::target::ConvertType;
+
+/// A field generated by an expression in the conversion between `Self` and `ty`
 pub(crate) struct GenerateArg {
-    pub into_ident: syn::Ident,
+    /// The other type, e.g. `B` or `dto::B`
+    pub ty: ConvertType,
+    /// `true` for `from(B)`, where `value` is the `B` being converted into `Self`
+    pub is_from: bool,
     pub field_ident: syn::Ident,
     pub expr: syn::Expr,
 }
 
-impl Parse for GenerateArg {
+/// The arguments of one `#[generate(...)]`, one for each type and field:
+///   - `B(field = expr)`: a field of `B` in `into(B)`
+///   - `into(A, B), field = expr, ..`: fields of the targets in `into`
+///   - `from(A, B), field = expr, ..`: fields of `Self` in `from`
+pub(crate) struct GenerateArgs(pub Vec<GenerateArg>);
+
+/// `field = expr`, also used by `split`
+#[derive(Clone)]
+pub(crate) struct GenerateField {
+    pub ident: Ident,
+    pub expr: syn::Expr,
+}
+
+impl Parse for GenerateField {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let into_ident = input.parse()?;
-        let content;
-        let _ = parenthesized!(content in input);
-        let field_ident = content.parse()?;
-        content.parse::<syn::Token![=]>()?;
-        let expr = content.parse()?;
-        Ok(Self {
-            into_ident,
-            field_ident,
-            expr,
-        })
+        let ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let expr = input.parse()?;
+        Ok(Self { ident, expr })
+    }
+}
+
+impl Parse for GenerateArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let fork = input.fork();
+        let direction = fork
+            .call(Ident::parse_any)
+            .ok()
+            .filter(|ident| (ident == "from" || ident == "into") && fork.peek(syn::token::Paren));
+
+        let (is_from, types, fields) = match direction {
+            Some(direction) => {
+                input.call(Ident::parse_any)?;
+                let content;
+                parenthesized!(content in input);
+                let types = content.parse_terminated(ConvertType::parse, Token![,])?;
+                input.parse::<Token![,]>()?;
+                let fields = input.parse_terminated(GenerateField::parse, Token![,])?;
+                (direction == "from", types.into_iter().collect(), fields)
+            }
+            None => {
+                let ty = ConvertType::new(syn::Type::Path(syn::TypePath {
+                    qself: None,
+                    path: input.parse()?,
+                }));
+                let content;
+                parenthesized!(content in input);
+                let fields = content.parse_terminated(GenerateField::parse, Token![,])?;
+                (false, vec![ty], fields)
+            }
+        };
+
+        Ok(Self(
+            types
+                .iter()
+                .flat_map(|ty: &ConvertType| {
+                    fields.iter().map(move |field| GenerateArg {
+                        ty: ty.clone(),
+                        is_from,
+                        field_ident: field.ident.clone(),
+                        expr: field.expr.clone(),
+                    })
+                })
+                .collect(),
+        ))
     }
 }
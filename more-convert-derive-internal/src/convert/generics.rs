@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Group, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{punctuated::Punctuated, GenericArgument, GenericParam, Generics, Ident, Type};
+
+use crate::get_last_path_segment;
+
+use super::{
+    container::{LeafConversion, Shape},
+    field_arg::SourceAccess,
+    target::ConvertTarget,
+};
+
+/// The generics of the impl of a conversion between the deriving type and the other type.
+///
+/// The generic arguments of the other type are mapped to the type parameters
+/// of the deriving type by position, e.g. `Wrapper<T> -> WrapperDto<U>` maps `T` to `U`.
+/// The other type is used as written, so a generic target must spell its arguments.
+pub(crate) struct ConvertGenerics {
+    /// The generics of the impl, including `generics(..)` and the inferred bounds
+    generics: Generics,
+    pub self_ty: TokenStream,
+    pub other_ty: TokenStream,
+    /// Type parameter of the deriving type -> generic argument of the other type
+    substitution: HashMap<String, TokenStream>,
+    /// Every type parameter of the impl
+    params: Vec<String>,
+    /// The context of `ConvertWith`, which bounds the fields instead of `Into`
+    context: Option<Type>,
+}
+
+impl ConvertGenerics {
+    pub(crate) fn new(
+        input_generics: &Generics,
+        self_ident: &Ident,
+        target: &ConvertTarget,
+    ) -> Self {
+        let conversion = &target.conversion;
+        let other = if conversion.from.is_ident(self_ident) {
+            &conversion.to
+        } else {
+            &conversion.from
+        };
+
+        let (_, ty_generics, _) = input_generics.split_for_impl();
+        let self_ty = if conversion.by_ref {
+            quote! { &#self_ident #ty_generics }
+        } else {
+            quote! { #self_ident #ty_generics }
+        };
+
+        let other_args = get_last_path_segment(&other.ty).and_then(|seg| match &seg.arguments {
+            syn::PathArguments::AngleBracketed(args) => Some(&args.args),
+            _ => None,
+        });
+        // the type parameters are mapped to the type arguments by position,
+        // skipping lifetimes and consts on both sides
+        let substitution = match other_args {
+            Some(args) => {
+                let params = input_generics.type_params().collect::<Vec<_>>();
+                let args = args
+                    .iter()
+                    .filter_map(|arg| match arg {
+                        GenericArgument::Type(arg) => Some(arg),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                if params.len() == args.len() {
+                    params
+                        .iter()
+                        .zip(args)
+                        .map(|(param, arg)| (param.ident.to_string(), arg.to_token_stream()))
+                        .collect()
+                } else {
+                    HashMap::new()
+                }
+            }
+            None => HashMap::new(),
+        };
+        let other_ty = other.to_token_stream();
+
+        // lifetimes must be declared before the other parameters
+        let (lifetimes, others): (Vec<_>, Vec<_>) = input_generics
+            .params
+            .iter()
+            .chain(&target.options.generics)
+            .cloned()
+            .partition(|param| matches!(param, GenericParam::Lifetime(_)));
+        let mut generics = input_generics.clone();
+        generics.params = lifetimes
+            .into_iter()
+            .chain(others)
+            .collect::<Punctuated<_, _>>();
+
+        let params = generics
+            .type_params()
+            .map(|param| param.ident.to_string())
+            .collect();
+
+        Self {
+            generics,
+            self_ty,
+            other_ty,
+            substitution,
+            params,
+            context: target.options.context.clone(),
+        }
+    }
+
+    /// Adds the bounds of the default conversion of a field of the deriving type.
+    ///
+    /// The bounds are put on the leaf types (e.g. the elements of `Vec` and `Option`),
+    /// and only when the leaf depends on a type parameter.
+    pub(crate) fn add_field_bound(
+        &mut self,
+        shape: &Shape,
+        is_from: bool,
+        fallible: bool,
+        access: SourceAccess,
+        leaf: Option<LeafConversion>,
+    ) {
+        if let Some(leaf) = leaf {
+            // the leaves are borrowed, see `gen_conversion`
+            for (ty, _) in shape.leaves(SourceAccess::Ref) {
+                self.add_leaf_conversion_bound(ty, leaf, is_from);
+            }
+            return;
+        }
+        for (leaf, shared) in shape.leaves(access) {
+            self.add_leaf_bound(leaf, shared, is_from, fallible, access);
+        }
+    }
+
+    /// The type of the other side of a leaf conversion is unknown, so only `Self` is bounded,
+    /// and only for `parse` and `display` (the numeric conversions are not generic)
+    fn add_leaf_conversion_bound(
+        &mut self,
+        leaf: &Type,
+        conversion: LeafConversion,
+        is_from: bool,
+    ) {
+        let leaf = leaf.to_token_stream();
+        if !self.mentions_param(leaf.clone()) {
+            return;
+        }
+        let predicates = &mut self.generics.make_where_clause().predicates;
+        match (conversion, is_from) {
+            (LeafConversion::Parse, true) => {
+                predicates.push(syn::parse_quote! { #leaf: std::str::FromStr });
+                predicates.push(syn::parse_quote! {
+                    <#leaf as std::str::FromStr>::Err:
+                        std::error::Error + std::marker::Send + std::marker::Sync + 'static
+                });
+            }
+            (LeafConversion::Display, false) => {
+                predicates.push(syn::parse_quote! { #leaf: std::fmt::Display });
+            }
+            _ => {}
+        }
+    }
+
+    /// `shared` is set when the leaf is cloned out of `Rc` or `Arc`
+    fn add_leaf_bound(
+        &mut self,
+        leaf: &Type,
+        shared: bool,
+        is_from: bool,
+        fallible: bool,
+        access: SourceAccess,
+    ) {
+        let self_leaf = leaf.to_token_stream();
+        if !self.mentions_param(self_leaf.clone()) {
+            return;
+        }
+        let other_leaf = substitute(self_leaf.clone(), &self.substitution);
+        let predicates = &mut self.generics.make_where_clause().predicates;
+        if access == SourceAccess::Clone || (shared && !is_from) {
+            predicates.push(syn::parse_quote! { #self_leaf: std::clone::Clone });
+        }
+        if shared && is_from {
+            predicates.push(syn::parse_quote! { #other_leaf: std::clone::Clone });
+        }
+        if access != SourceAccess::Ref && self_leaf.to_string() == other_leaf.to_string() {
+            return;
+        }
+
+        let (source, target) = match (is_from, access) {
+            (true, _) => (other_leaf, self_leaf),
+            (false, SourceAccess::Ref) => (quote! { for<'r> &'r #self_leaf }, other_leaf),
+            (false, _) => (self_leaf, other_leaf),
+        };
+        if let Some(context) = &self.context {
+            if fallible {
+                predicates.push(syn::parse_quote! {
+                    #source: more_convert::TryConvertWith<#target, #context>
+                });
+                predicates.push(syn::parse_quote! {
+                    <#source as more_convert::TryConvertWith<#target, #context>>::Error:
+                        std::error::Error + std::marker::Send + std::marker::Sync + 'static
+                });
+            } else {
+                predicates.push(syn::parse_quote! {
+                    #source: more_convert::ConvertWith<#target, #context>
+                });
+            }
+        } else if fallible {
+            predicates.push(syn::parse_quote! {
+                #source: std::convert::TryInto<#target>
+            });
+            predicates.push(syn::parse_quote! {
+                <#source as std::convert::TryInto<#target>>::Error:
+                    std::error::Error + std::marker::Send + std::marker::Sync + 'static
+            });
+        } else {
+            predicates.push(syn::parse_quote! {
+                #source: std::convert::Into<#target>
+            });
+        }
+    }
+
+    /// Returns `(impl_generics, where_clause)`
+    pub(crate) fn split(&self) -> (TokenStream, TokenStream) {
+        let (impl_generics, _, where_clause) = self.generics.split_for_impl();
+        (
+            impl_generics.to_token_stream(),
+            where_clause.to_token_stream(),
+        )
+    }
+
+    fn mentions_param(&self, tokens: TokenStream) -> bool {
+        tokens.into_iter().any(|tt| match tt {
+            TokenTree::Ident(ident) => self.params.contains(&ident.to_string()),
+            TokenTree::Group(group) => self.mentions_param(group.stream()),
+            _ => false,
+        })
+    }
+}
+
+/// Replaces the type parameters in `tokens`
+fn substitute(tokens: TokenStream, substitution: &HashMap<String, TokenStream>) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Ident(ident) => match substitution.get(&ident.to_string()) {
+                Some(arg) => arg.clone(),
+                None => ident.into_token_stream(),
+            },
+            TokenTree::Group(group) => {
+                let mut new =
+                    Group::new(group.delimiter(), substitute(group.stream(), substitution));
+                new.set_span(group.span());
+                new.into_token_stream()
+            }
+            tt => tt.into_token_stream(),
+        })
+        .collect()
+}
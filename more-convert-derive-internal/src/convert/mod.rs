@@ -1,80 +1,273 @@
-use field::ConvertField;
-use generate::GenerateArg;
-use proc_macro2::TokenStream;
-use quote::ToTokens;
-use syn::{Ident, ImplGenerics, TypeGenerics, WhereClause};
-use target::{Conversion, ConvertArgs};
+use body::{BodyGenerator, ErrorMode, FieldToken};
+use field::{ConvertAttrs, ConvertField};
+use field_arg::ConvertFieldMap;
+use flatten::FlattenDef;
+use generate::{GenerateArg, GenerateArgs};
+use generics::ConvertGenerics;
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{spanned::Spanned, Ident, Member};
+use target::{merge_targets, ConvertArgs, ConvertTarget, RenameRule, TargetConstruct};
+use variant::ConvertVariant;
 
-use crate::require_named_field_struct;
+use crate::{check_duplicate, require_struct, unraw};
 
+mod body;
+mod container;
 mod field;
 mod field_arg;
+mod flatten;
+mod function;
 mod generate;
+mod generics;
 mod target;
+mod variant;
+
+pub use function::convert_fn;
 
 pub fn derive_convert(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    expand(&input, &[])
+}
+
+/// Continues the derive of a struct with `flatten` fields, see [`FlattenDef`]
+pub fn convert_flatten(input: TokenStream) -> syn::Result<TokenStream> {
+    let def: FlattenDef = syn::parse2(input)?;
+    expand(&def.input, &def.resolved)
+}
+
+/// `resolved` is the definitions of the structs of the `flatten` fields resolved so far
+fn expand(input: &syn::DeriveInput, resolved: &[(Ident, FlattenDef)]) -> syn::Result<TokenStream> {
     let self_ident = &input.ident;
-    let fields = require_named_field_struct(&input)?;
 
     // 1. Parse attributes to get a list of conversions and generations
-    let mut conversions = Vec::new();
+    let mut targets = Vec::new();
     let mut generates: Vec<GenerateArg> = vec![];
+    let mut transparent = None;
     for attr in &input.attrs {
         if attr.path().is_ident("convert") {
             let args: ConvertArgs = attr.parse_args()?;
-            conversions.extend(args.into_conversions(self_ident)?);
+            if let Some(ident) = args.transparent() {
+                check_duplicate!(ident.span(), transparent);
+                transparent = Some(ident.clone());
+            }
+            targets.extend(args.into_targets(self_ident)?);
         }
         if attr.path().is_ident("generate") {
-            generates.push(attr.parse_args()?);
+            generates.extend(attr.parse_args::<GenerateArgs>()?.0);
+        }
+    }
+    let targets = merge_targets(targets)?;
+
+    if let syn::Data::Enum(data) = &input.data {
+        if let Some(transparent) = transparent {
+            return Err(syn::Error::new(
+                transparent.span(),
+                "`transparent` is not supported on enums",
+            ));
+        }
+        if let Some(generate) = generates.first() {
+            return Err(syn::Error::new(
+                generate.ty.span(),
+                "`generate` is not supported on enums",
+            ));
+        }
+
+        // 2. Parse variant information
+        let variants = data
+            .variants
+            .iter()
+            .map(|v| ConvertVariant::from_variant(v, self_ident))
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        // 3. Validate variant-level and field-level attributes
+        for variant in &variants {
+            validate_target_attributes(
+                &unraw(variant.ident),
+                variant.ident.span(),
+                &variant.attrs,
+                &targets,
+            )?;
+            for field in &variant.fields {
+                validate_target_attributes(
+                    &field.name(),
+                    field.member.span(),
+                    &field.attrs,
+                    &targets,
+                )?;
+            }
+        }
+
+        // the `match` of the variants of a `remote` enum is exhaustive anyway
+        if let Some(target) = targets
+            .iter()
+            .find(|t| t.options.exhaustive && !t.options.remote)
+        {
+            return Err(syn::Error::new(
+                target.conversion.from.span(),
+                "`exhaustive` is not supported on enums",
+            ));
+        }
+        if let Some(target) = targets.iter().find(|t| t.options.default_rest.is_some()) {
+            return Err(syn::Error::new(
+                target.conversion.from.span(),
+                "`default_rest` is not supported on enums",
+            ));
+        }
+        if let Some(target) = targets.iter().find(|t| t.options.construct.is_some()) {
+            return Err(syn::Error::new(
+                target.conversion.from.span(),
+                "`constructor` and `builder` are not supported on enums",
+            ));
         }
+        if let Some(target) = targets.iter().find(|t| !t.options.merge.is_empty()) {
+            return Err(syn::Error::new(
+                target.conversion.from.span(),
+                "`merge` is not supported on enums",
+            ));
+        }
+
+        // 4. Generate `impl From` or `impl TryFrom` for each conversion
+        return targets
+            .iter()
+            .map(|target| {
+                let mut generics = ConvertGenerics::new(&input.generics, self_ident, target);
+                let (mode, body) = match gen_via_body(target) {
+                    Some(via) => via,
+                    None => variant::gen_enum_body(target, &variants, self_ident, &mut generics)?,
+                };
+                gen_impl(&generics, target, self_ident, mode, body)
+            })
+            .collect();
     }
 
+    let fields = require_struct(input)?;
+
     // 2. Parse field information
-    let fields = fields
-        .named
+    let mut impls = match transparent {
+        Some(transparent) => gen_transparent(input, fields, &transparent)?,
+        None => TokenStream::new(),
+    };
+    let mut fields = fields
         .iter()
-        .map(|f| ConvertField::from_field(f, self_ident))
+        .enumerate()
+        .map(|(i, f)| ConvertField::from_field(f, i, self_ident))
         .collect::<syn::Result<Vec<_>>>()?;
 
     // 3. Validate field-level attributes
-    validate_field_attributes(&fields, &conversions, &generates)?;
-
-    // 4. Generate `impl From` for each conversion
-    let generics = input.generics.split_for_impl();
-    conversions
-        .into_iter()
-        .map(|conversion| gen_from_impl(&generics, &conversion, &fields, &generates, self_ident))
-        .collect::<syn::Result<TokenStream>>()
+    for field in &fields {
+        validate_target_attributes(&field.name(), field.member.span(), &field.attrs, &targets)?;
+    }
+    validate_generates(&targets, &generates, self_ident)?;
+
+    // 4. Resolve the structs of `flatten` fields through their flatten macros one by one
+    if let Some((field, ty)) = flatten::resolve_fields(&mut fields, resolved)? {
+        return flatten::gen_resolve(input, resolved, &field, ty);
+    }
+    impls.extend(flatten::gen_flatten_macro(input, resolved));
+    impls.extend(flatten::gen_flatten_impls(input, &fields));
+
+    // 5. Generate `impl From` or `impl TryFrom` for each conversion
+    for target in &targets {
+        let mut generics = ConvertGenerics::new(&input.generics, self_ident, target);
+        if let Some((mode, body)) = gen_via_body(target) {
+            impls.extend(gen_impl(&generics, target, self_ident, mode, body)?);
+            continue;
+        }
+        let is_from = target.conversion.to.is_ident(self_ident);
+        let (mode, body) = gen_struct_body(
+            target,
+            &fields,
+            &generates,
+            is_from,
+            &target_ctor(target),
+            &mut generics,
+        )?;
+        impls.extend(gen_impl(&generics, target, self_ident, mode, body)?);
+    }
+    Ok(impls)
 }
 
-fn validate_field_attributes(
-    fields: &[ConvertField],
-    conversions: &[Conversion],
-    generates: &[GenerateArg],
-) -> syn::Result<()> {
-    for field in fields {
-        for conv in field.target.keys() {
-            if !conversions.contains(conv) {
-                return Err(syn::Error::new(
-                    field.ident.span(),
-                    format!(
-                        "field '{}' specifies `convert` for `{} -> {}`, but the struct-level attribute is missing.\n\
-                        \n\
-                        Help: Add `#[convert(from({}))]` or `#[convert(into({}))]` to the struct definition.",
-                        field.ident, conv.from, conv.to, conv.from, conv.to
-                    ),
-                ));
+/// Generates `From<Inner> for Self` and `From<Self> for Inner` for a single-field struct
+fn gen_transparent(
+    input: &syn::DeriveInput,
+    fields: &syn::Fields,
+    transparent: &Ident,
+) -> syn::Result<TokenStream> {
+    let mut iter = fields.iter();
+    let (Some(field), None) = (iter.next(), iter.next()) else {
+        return Err(syn::Error::new(
+            transparent.span(),
+            "`transparent` requires a struct with exactly one field",
+        ));
+    };
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+
+    let self_ident = &input.ident;
+    let inner = &field.ty;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics std::convert::From<#inner> for #self_ident #ty_generics #where_clause {
+            fn from(value: #inner) -> Self {
+                Self { #member: value }
             }
         }
+
+        impl #impl_generics std::convert::From<#self_ident #ty_generics> for #inner #where_clause {
+            fn from(value: #self_ident #ty_generics) -> Self {
+                value.#member
+            }
+        }
+    })
+}
+
+fn validate_target_attributes(
+    name: &str,
+    span: Span,
+    attrs: &ConvertAttrs,
+    targets: &[ConvertTarget],
+) -> syn::Result<()> {
+    for conv in attrs.target.keys() {
+        if !targets.iter().any(|t| t.conversion == *conv) {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "'{}' specifies `convert` for `{}`, but the struct-level attribute is missing.\n\
+                    \n\
+                    Help: Add `#[convert(from({}))]` or `#[convert(into({}))]` to the definition.",
+                    name, conv, conv.from, conv.to
+                ),
+            ));
+        }
     }
 
+    Ok(())
+}
+
+fn validate_generates(
+    targets: &[ConvertTarget],
+    generates: &[GenerateArg],
+    self_ident: &Ident,
+) -> syn::Result<()> {
     for generate in generates {
-        if !conversions.iter().any(|c| c.to == generate.into_ident) {
+        let ty = &generate.ty;
+        let exists = targets.iter().any(|t| {
+            let conversion = &t.conversion;
+            if generate.is_from {
+                conversion.from == *ty && conversion.to.is_ident(self_ident)
+            } else {
+                conversion.to == *ty && conversion.from.is_ident(self_ident)
+            }
+        });
+        if !exists {
+            let direction = if generate.is_from { "from" } else { "into" };
             return Err(syn::Error::new(
-                generate.into_ident.span(),
+                ty.span(),
                 format!(
-                    "`generate` for `{}` is specified, but there is no `into({})` or `from_into({})` attribute",
-                    generate.into_ident, generate.into_ident, generate.into_ident
+                    "`generate` for `{direction}({ty})` is specified, \
+                    but there is no `{direction}({ty})` or `from_into({ty})` attribute",
                 ),
             ));
         }
@@ -83,74 +276,622 @@ fn validate_field_attributes(
     Ok(())
 }
 
-fn gen_from_impl(
-    generics: &(ImplGenerics, TypeGenerics, Option<&WhereClause>),
-    conversion: &Conversion,
+/// Generates the body of a conversion chained through `via`, if any
+fn gen_via_body(target: &ConvertTarget) -> Option<(ErrorMode, TokenStream)> {
+    if target.options.via.is_empty() {
+        return None;
+    }
+    let mode = error_mode(target, false);
+    let generator = BodyGenerator {
+        from: &target.conversion.from,
+        into: &target.conversion.to,
+        mode,
+        rest: None,
+        construct: None,
+    };
+    Some((mode, generator.gen_via(&target.options.via)))
+}
+
+/// Determines the error mode of a conversion.
+///
+/// A conversion is fallible if it is declared with a `try_*` keyword or `collect_errors`,
+/// or if `fallible` is set because of its fields (e.g. `try_map`).
+fn error_mode(target: &ConvertTarget, fallible: bool) -> ErrorMode {
+    if target.options.collect_errors {
+        ErrorMode::Collect
+    } else if target.fallible || fallible {
+        ErrorMode::First
+    } else {
+        ErrorMode::Infallible
+    }
+}
+
+/// Returns the member of the other side of the conversion,
+/// applying `rename`, `name` or `index`, or else the rename rule of the target
+fn renamed_member(
+    member: &Member,
+    rename: Option<&Member>,
+    rule: &RenameRule,
+) -> syn::Result<Member> {
+    match (rename, member) {
+        (Some(rename), _) => Ok(rename.clone()),
+        (None, Member::Named(ident)) => Ok(Member::Named(rule.apply(ident)?)),
+        (None, Member::Unnamed(_)) => Ok(member.clone()),
+    }
+}
+
+/// Returns the local of `member` of the destructured source, binding it at the first use
+fn bind_source(bindings: &mut Vec<(Member, Ident)>, member: &Member) -> Ident {
+    if let Some((_, local)) = bindings.iter().find(|(bound, _)| bound == member) {
+        return local.clone();
+    }
+    let local = format_ident!("source_{}", bindings.len(), span = Span::mixed_site());
+    bindings.push((member.clone(), local.clone()));
+    local
+}
+
+/// Whether `ident` appears anywhere in `tokens`
+fn mentions(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(tt) => tt == *ident,
+        TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
+
+/// Collects the members read as `value.member` in `tokens`, e.g. `a` of `value.a.len()`.
+///
+/// Method calls such as `value.len()` are not members.
+fn read_members(tokens: TokenStream, members: &mut Vec<Member>) {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    for (i, tt) in tokens.iter().enumerate() {
+        let member = match (tt, tokens.get(i + 1), tokens.get(i + 2), tokens.get(i + 3)) {
+            (TokenTree::Ident(value), Some(TokenTree::Punct(dot)), Some(member), next)
+                if value == "value" && dot.as_char() == '.' =>
+            {
+                match member {
+                    TokenTree::Ident(ident)
+                        if !matches!(next, Some(TokenTree::Group(group))
+                            if group.delimiter() == Delimiter::Parenthesis) =>
+                    {
+                        Some(Member::Named(ident.clone()))
+                    }
+                    // `value.0.1` is lexed as the float `0.1`
+                    TokenTree::Literal(lit) => lit
+                        .to_string()
+                        .split('.')
+                        .next()
+                        .and_then(|index| index.parse().ok())
+                        .map(|index| {
+                            Member::Unnamed(syn::Index {
+                                index,
+                                span: lit.span(),
+                            })
+                        }),
+                    _ => None,
+                }
+            }
+            (TokenTree::Group(group), ..) => {
+                read_members(group.stream(), members);
+                None
+            }
+            _ => None,
+        };
+        if let Some(member) = member.filter(|member| !members.contains(member)) {
+            members.push(member);
+        }
+    }
+}
+
+/// Generates the body of a conversion between structs.
+///
+/// The expressions reading `value` (`map`, `map_struct`, `generate` and `split`) are evaluated first,
+/// then `value` is destructured into the fields that are converted,
+/// so that moving a field never conflicts with a borrow of `value`.
+///
+/// `is_from` is set when the fields are those of the target, constructed by `ctor`.
+fn gen_struct_body(
+    target: &ConvertTarget,
     fields: &[ConvertField],
     generates: &[GenerateArg],
-    self_ident: &Ident,
-) -> syn::Result<TokenStream> {
-    let from_ident = &conversion.from;
-    let into_ident = &conversion.to;
+    is_from: bool,
+    ctor: &TokenStream,
+    generics: &mut ConvertGenerics,
+) -> syn::Result<(ErrorMode, TokenStream)> {
+    let conversion = &target.conversion;
+    let from_ty = &conversion.from;
+    let into_ty = &conversion.to;
 
-    let mut field_idents = Vec::new();
+    let construct = target.options.construct.as_ref();
+    if let (Some(_), Some(base)) = (construct, &target.options.default_rest) {
+        return Err(syn::Error::new(
+            base.span(),
+            "`default_rest` can not be combined with `constructor` or `builder`",
+        ));
+    }
+    // A field whose map returns a `Result` makes the whole conversion fallible
+    let mode = error_mode(
+        target,
+        construct.is_some_and(TargetConstruct::is_fallible)
+            || fields
+                .iter()
+                .any(|field| field.is_fallible(conversion, is_from)),
+    );
+
+    let rule = &target.options.rename_rule;
     let mut field_tokens = Vec::new();
+    // `let` statements evaluated before `value` is destructured
+    let mut evaluations = Vec::new();
+    // source member -> local of the destructured field
+    let mut bindings: Vec<(Member, Ident)> = Vec::new();
+    // source members read only through `value` by maps
+    let mut mapped: Vec<Member> = Vec::new();
+
+    // `generate` fills a field of the target, which is `Self` in `from`
+    let other_ty = if is_from { from_ty } else { into_ty };
+    let mut generated = Vec::new();
+    for (i, g) in generates.iter().enumerate() {
+        if g.is_from == is_from && g.ty == *other_ty {
+            generated.push(&g.field_ident);
+            let local = format_ident!("generate_{}", i, span = Span::mixed_site());
+            let expr = &g.expr;
+            read_members(expr.to_token_stream(), &mut mapped);
+            evaluations.push(quote! { let #local = #expr; });
+            field_tokens.push(FieldToken::converted(
+                g.field_ident.to_token_stream(),
+                unraw(&g.field_ident),
+                local.to_token_stream(),
+                false,
+            ));
+        }
+    }
 
-    // Handle `generate` attributes for `Into` conversions
-    if from_ident == self_ident {
-        for g in generates {
-            if g.into_ident == *into_ident {
-                field_idents.push(g.field_ident.to_token_stream());
-                field_tokens.push(g.expr.to_token_stream());
+    // `merge` consumes fields of the source, which are not converted individually
+    let mut consumed: Vec<&Member> = Vec::new();
+    for merge in &target.options.merge {
+        for member in &merge.sources {
+            if consumed.contains(&member) {
+                return Err(syn::Error::new(
+                    member.span(),
+                    format!(
+                        "`{}` is consumed by `merge` more than once",
+                        field::member_name(member)
+                    ),
+                ));
             }
+            if !is_from && !fields.iter().any(|field| field.member == *member) {
+                return Err(syn::Error::new(
+                    member.span(),
+                    format!(
+                        "`{}` is not a field of the struct",
+                        field::member_name(member)
+                    ),
+                ));
+            }
+            consumed.push(member);
         }
     }
+    for merge in &target.options.merge {
+        let locals = merge
+            .sources
+            .iter()
+            .map(|member| bind_source(&mut bindings, member))
+            .collect::<Vec<_>>();
+        let func = &merge.func;
+        let merged = Ident::new("merge", Span::mixed_site());
+        field_tokens.push(FieldToken::converted(
+            merge.target.to_token_stream(),
+            unraw(&merge.target),
+            quote! {{
+                let #merged = #func;
+                #merged(#( #locals ),*)
+            }},
+            false,
+        ));
+    }
 
-    for field in fields {
+    for (i, field) in fields.iter().enumerate() {
         let arg = field.get_arg_for_conversion(conversion);
 
         if arg.ignore {
             continue;
         }
 
-        let (target_field_ident, source_field_ident) = if *into_ident == *self_ident {
+        // in `into`, the field is merged; in `from`, it is the result of the merge
+        let merged = if is_from {
+            target
+                .options
+                .merge
+                .iter()
+                .any(|merge| field.member == Member::Named(merge.target.clone()))
+        } else {
+            consumed.contains(&&field.member)
+        };
+        if merged {
+            if arg.map.is_some()
+                || arg.rename.is_some()
+                || arg.path.is_some()
+                || arg.split.is_some()
+            {
+                return Err(syn::Error::new(
+                    field.member.span(),
+                    format!(
+                        "`{}` is handled by `merge` in `{}` and can not be converted individually",
+                        field.name(),
+                        conversion
+                    ),
+                ));
+            }
+            continue;
+        }
+
+        if let Some(split) = &arg.split {
+            if is_from {
+                return Err(syn::Error::new(
+                    field.member.span(),
+                    "`split` is only supported in `into` conversions",
+                ));
+            }
+            // the expressions refer to the field by its name, or `_0` for tuple structs
+            let name = match &field.member {
+                Member::Named(ident) => ident.clone(),
+                Member::Unnamed(index) => format_ident!("_{}", index.index),
+            };
+            let member = &field.member;
+            for (j, split) in split.iter().enumerate() {
+                let local = format_ident!("split_{}_{}", i, j, span = Span::mixed_site());
+                let expr = &split.expr;
+                evaluations.push(quote! {
+                    let #local = {
+                        let #name = &value.#member;
+                        #expr
+                    };
+                });
+                field_tokens.push(FieldToken::converted(
+                    split.ident.to_token_stream(),
+                    unraw(&split.ident),
+                    local.to_token_stream(),
+                    false,
+                ));
+            }
+            continue;
+        }
+
+        if let Some(nested) = &field.flatten {
+            flatten::check_flatten_arg(field, &arg)?;
+            if is_from {
+                let value = flatten::gather_fields(nested, field.ty, target, mode, &mut bindings)?;
+                field_tokens.push(FieldToken {
+                    target: field.member.to_token_stream(),
+                    value,
+                    assigned: false,
+                });
+            } else {
+                // the local is a reference in `into_ref`
+                let local = bind_source(&mut bindings, &field.member);
+                flatten::spread_fields(
+                    nested,
+                    target,
+                    &local.to_token_stream(),
+                    &field.name(),
+                    mode,
+                    &mut field_tokens,
+                )?;
+            }
+            continue;
+        }
+
+        // `source` reads a nested field of the source, `target_path` writes one of the target
+        let other = match &arg.path {
+            Some(path) => {
+                path.check(is_from)?;
+                path.first.clone()
+            }
+            None => renamed_member(&field.member, arg.rename.as_ref(), rule)?,
+        };
+        let nested = arg.path.as_ref().map_or(&[][..], |path| &path.rest);
+        let (target_member, source_member) = if is_from {
             // impl From<T> for Self
-            let source_name = arg
-                .rename
-                .as_ref()
-                .map(|l| l.value())
-                .unwrap_or_else(|| field.ident.to_string());
-            (
-                field.ident.to_token_stream(),
-                Ident::new(&source_name, field.ident.span()).to_token_stream(),
-            )
+            (field.member.clone(), other)
         } else {
             // impl From<Self> for T
-            let target_name = arg
-                .rename
-                .as_ref()
-                .map(|l| l.value())
-                .unwrap_or_else(|| field.ident.to_string());
-            (
-                Ident::new(&target_name, field.ident.span()).to_token_stream(),
-                field.ident.to_token_stream(),
+            (other, field.member.clone())
+        };
+        if consumed.contains(&&source_member) {
+            return Err(syn::Error::new(
+                field.member.span(),
+                format!(
+                    "`{}` is consumed by `merge` in `{}` and can not be converted individually",
+                    field::member_name(&source_member),
+                    conversion
+                ),
+            ));
+        }
+
+        if arg.map.is_none() {
+            generics.add_field_bound(
+                &arg.shape(field.ty),
+                is_from,
+                mode.is_fallible(),
+                arg.access(conversion.by_ref),
+                arg.leaf,
+            );
+        }
+
+        let (target_expr, source) = match &arg.path {
+            Some(path) if is_from => (target_member.to_token_stream(), path.name()),
+            _ => (
+                quote! { #target_member #( .#nested )* },
+                field::member_name(&source_member),
+            ),
+        };
+        let reads_value = arg.map.as_ref().is_some_and(ConvertFieldMap::reads_value);
+        let (token, is_result) = if reads_value {
+            let (token, is_result) = arg.to_token(
+                field.ty,
+                &TokenStream::new(),
+                &source,
+                mode.is_fallible(),
+                target,
+                is_from,
+            );
+            let local = format_ident!("map_{}", i, span = Span::mixed_site());
+            evaluations.push(quote! { let #local = #token; });
+            read_members(token.clone(), &mut mapped);
+            (local.to_token_stream(), is_result)
+        } else {
+            let local = bind_source(&mut bindings, &source_member);
+            // the locals are references in `into_ref`
+            let source_expr = if is_from {
+                quote! { #local #( .#nested )* }
+            } else {
+                local.to_token_stream()
+            };
+            arg.to_token(
+                field.ty,
+                &source_expr,
+                &source,
+                mode.is_fallible(),
+                target,
+                is_from,
             )
         };
+        field_tokens.push(FieldToken {
+            assigned: !is_from && !nested.is_empty(),
+            ..FieldToken::converted(target_expr, source, token, is_result)
+        });
+    }
 
-        let token = arg.map.to_token(&source_field_ident);
+    // a generated field must not be converted from a field too
+    for ident in generated {
+        let name = ident.to_string();
+        let count = field_tokens
+            .iter()
+            .filter(|token| token.target.to_string() == name)
+            .count();
+        if count > 1 {
+            let message = if is_from {
+                format!(
+                    "`{}` is generated by `generate` in `{}`, ignore the field, e.g. `#[convert(from({}), ignore)]`",
+                    unraw(ident),
+                    conversion,
+                    from_ty
+                )
+            } else {
+                format!(
+                    "`{}` of `{}` is generated by `generate` and converted from a field too, ignore the field in `{}`",
+                    unraw(ident),
+                    into_ty,
+                    conversion
+                )
+            };
+            return Err(syn::Error::new(ident.span(), message));
+        }
+    }
 
-        field_idents.push(target_field_ident);
-        field_tokens.push(token);
+    // an unused field would be bound to a local of an unknown type
+    if let Some(TargetConstruct::Constructor { expr, .. }) = construct {
+        let expr = expr.to_token_stream();
+        let unused = field_tokens
+            .iter()
+            .filter(|token| !token.assigned)
+            .enumerate()
+            .find(|(i, token)| {
+                !mentions(expr.clone(), &body::constructor_local(*i, &token.target))
+            });
+        if let Some((i, token)) = unused {
+            return Err(syn::Error::new(
+                token.target.span(),
+                format!(
+                    "`{}` is not used by `constructor`, ignore the field, e.g. `#[convert({}({}), ignore)]`",
+                    unraw(&body::constructor_local(i, &token.target)),
+                    if is_from { "from" } else { "into" },
+                    if is_from { from_ty } else { into_ty }
+                ),
+            ));
+        }
     }
 
-    let (impl_generics, ty_generics, where_clause) = generics;
-    Ok(quote::quote! {
-        impl #impl_generics std::convert::From<#from_ident> for #into_ident #ty_generics #where_clause {
-            fn from(value: #from_ident) -> Self {
-                Self {
-                    #( #field_idents: #field_tokens, )*
+    // the setters of a builder are named after the fields
+    if let Some(TargetConstruct::Builder { .. }) = construct {
+        if let Some(token) = field_tokens
+            .iter()
+            .find(|token| matches!(syn::parse2(token.target.clone()), Ok(Member::Unnamed(_))))
+        {
+            return Err(syn::Error::new(
+                token.target.span(),
+                "`builder` requires the named fields of the target",
+            ));
+        }
+    }
+
+    // `exhaustive` mentions every field of the source instead of `..`
+    let mut rest = quote! { .. };
+    let mut discards = Vec::new();
+    if target.options.exhaustive {
+        rest = TokenStream::new();
+        if !is_from {
+            for field in fields {
+                if !bindings.iter().any(|(member, _)| *member == field.member) {
+                    discards.push(field.member.clone());
                 }
             }
+        } else {
+            // the fields read as `value.field` by maps and `generate` are handled too
+            for member in mapped {
+                if !bindings.iter().any(|(bound, _)| *bound == member)
+                    && !discards.contains(&member)
+                {
+                    discards.push(member);
+                }
+            }
+        }
+        for member in &target.options.discard {
+            if bindings.iter().any(|(bound, _)| bound == member) || discards.contains(member) {
+                return Err(syn::Error::new(
+                    member.span(),
+                    format!(
+                        "`{}` is already handled by `{}`, remove it from `discard`",
+                        field::member_name(member),
+                        conversion
+                    ),
+                ));
+            }
+            discards.push(member.clone());
+        }
+    }
+
+    let destructure = (!bindings.is_empty() || target.options.exhaustive).then(|| {
+        let path = from_ty.to_pattern_path();
+        let bindings = bindings
+            .iter()
+            .map(|(member, local)| quote! { #member: #local })
+            .chain(discards.iter().map(|member| quote! { #member: _ }));
+        // rustc reports the fields missing from the pattern at this span
+        let pattern = quote_spanned! {from_ty.span()=>
+            #path { #( #bindings, )* #rest }
+        };
+        quote! {
+            let #pattern = value;
+        }
+    });
+
+    // evaluated before `value` is destructured, like `map`
+    let rest = target.options.default_rest.as_ref().map(|base| {
+        let local = Ident::new("rest", Span::mixed_site());
+        evaluations.push(quote! { let #local = #base; });
+        local.to_token_stream()
+    });
+
+    let generator = BodyGenerator {
+        from: from_ty,
+        into: into_ty,
+        mode,
+        rest,
+        construct,
+    };
+    let construct = generator.gen_construct(ctor, &field_tokens);
+    Ok((
+        mode,
+        quote! {
+            #( #evaluations )*
+            #destructure
+            #construct
+        },
+    ))
+}
+
+/// The path constructing the target of a conversion,
+/// `Self` unless the impl is on the source (`context`) or on the deriving type (`remote`)
+fn target_ctor(target: &ConvertTarget) -> TokenStream {
+    if target.options.context.is_some() || target.options.remote {
+        target.conversion.to.to_pattern_path()
+    } else {
+        quote! { Self }
+    }
+}
+
+fn gen_impl(
+    generics: &ConvertGenerics,
+    target: &ConvertTarget,
+    self_ident: &Ident,
+    mode: ErrorMode,
+    body: TokenStream,
+) -> syn::Result<TokenStream> {
+    let (from_ty, into_ty) = if target.conversion.from.is_ident(self_ident) {
+        (&generics.self_ty, &generics.other_ty)
+    } else {
+        (&generics.other_ty, &generics.self_ty)
+    };
+    let (impl_generics, where_clause) = generics.split();
+
+    // inherent functions of the deriving type, which is local unlike the remote type
+    if target.options.remote {
+        let (function, self_ty) = if target.conversion.from.is_ident(self_ident) {
+            (quote! { to_remote }, from_ty)
+        } else {
+            (quote! { from_remote }, into_ty)
+        };
+        let output = match mode.error_type() {
+            Some(error) => quote! { std::result::Result<#into_ty, #error> },
+            None => into_ty.clone(),
+        };
+        return Ok(quote! {
+            impl #impl_generics #self_ty #where_clause {
+                pub fn #function(value: #from_ty) -> #output {
+                    #body
+                }
+            }
+        });
+    }
+
+    // `ctx` can be used by `map`, and is passed to the nested conversions
+    if let Some(context) = &target.options.context {
+        if let Some(error) = mode.error_type() {
+            return Ok(quote! {
+                impl #impl_generics more_convert::TryConvertWith<#into_ty, #context> for #from_ty #where_clause {
+                    type Error = #error;
+
+                    #[allow(unused_variables)]
+                    fn try_convert_with(
+                        self,
+                        ctx: &#context,
+                    ) -> std::result::Result<#into_ty, Self::Error> {
+                        let value = self;
+                        #body
+                    }
+                }
+            });
+        }
+
+        return Ok(quote! {
+            impl #impl_generics more_convert::ConvertWith<#into_ty, #context> for #from_ty #where_clause {
+                #[allow(unused_variables)]
+                fn convert_with(self, ctx: &#context) -> #into_ty {
+                    let value = self;
+                    #body
+                }
+            }
+        });
+    }
+
+    if let Some(error) = mode.error_type() {
+        return Ok(quote! {
+            impl #impl_generics std::convert::TryFrom<#from_ty> for #into_ty #where_clause {
+                type Error = #error;
+
+                fn try_from(value: #from_ty) -> std::result::Result<Self, Self::Error> {
+                    #body
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics std::convert::From<#from_ty> for #into_ty #where_clause {
+            fn from(value: #from_ty) -> Self {
+                #body
+            }
         }
     })
 }
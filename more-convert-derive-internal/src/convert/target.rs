@@ -1,55 +1,455 @@
-use syn::{parenthesized, parse::Parse, punctuated::Punctuated, Ident, Meta, Token};
+use convert_case::{Case, Casing};
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::{
+    bracketed, ext::IdentExt, parenthesized, parse::Parse, punctuated::Punctuated,
+    spanned::Spanned, Expr, GenericParam, Ident, LitStr, Member, Meta, Token, Type,
+};
+
+use crate::{check_duplicate, from_str_to_case, unraw};
+
+/// The type of one side of a conversion, e.g. `B`, `dto::User` or `Page<UserDto>`.
+///
+/// Types are compared by their normalized tokens,
+/// so `Page<UserDto>` and `Page < UserDto >` are the same type.
+#[derive(Clone)]
+pub(crate) struct ConvertType {
+    pub ty: Type,
+    key: String,
+}
+
+impl ConvertType {
+    pub(crate) fn new(ty: Type) -> Self {
+        let key = ty.to_token_stream().to_string();
+        Self { ty, key }
+    }
+
+    /// Whether this type is the bare identifier, e.g. the deriving type
+    pub(crate) fn is_ident(&self, ident: &Ident) -> bool {
+        *ident == self.key
+    }
+
+    pub(crate) fn span(&self) -> Span {
+        self.ty.span()
+    }
+
+    /// The path of the type without generic arguments,
+    /// which can be used in patterns (e.g. `Page::Variant { .. }`).
+    pub(crate) fn to_pattern_path(&self) -> TokenStream {
+        match &self.ty {
+            Type::Path(path) if path.qself.is_none() => {
+                let mut path = path.path.clone();
+                for segment in &mut path.segments {
+                    segment.arguments = syn::PathArguments::None;
+                }
+                path.to_token_stream()
+            }
+            ty => ty.to_token_stream(),
+        }
+    }
+}
+
+impl From<&Ident> for ConvertType {
+    fn from(ident: &Ident) -> Self {
+        Self::new(syn::parse_quote!(#ident))
+    }
+}
+
+impl Parse for ConvertType {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(Self::new(input.parse()?))
+    }
+}
+
+impl ToTokens for ConvertType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.ty.to_tokens(tokens);
+    }
+}
+
+impl std::fmt::Display for ConvertType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.key)
+    }
+}
+
+impl std::fmt::Debug for ConvertType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.key)
+    }
+}
+
+impl PartialEq for ConvertType {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for ConvertType {}
+
+impl std::hash::Hash for ConvertType {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+impl PartialOrd for ConvertType {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ConvertType {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key.cmp(&other.key)
+    }
+}
 
 #[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub(crate) struct Conversion {
-    pub from: Ident,
-    pub to: Ident,
+    pub from: ConvertType,
+    pub to: ConvertType,
+    /// `impl From<&Self>`, set by `into_ref` and `try_into_ref`
+    pub by_ref: bool,
+}
+
+impl std::fmt::Display for Conversion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let by_ref = if self.by_ref { "&" } else { "" };
+        write!(f, "{}{} -> {}", by_ref, self.from, self.to)
+    }
+}
+
+/// A conversion requested by a struct-level attribute.
+///
+/// `fallible` is set by the `try_*` keywords and makes the macro emit
+/// `impl TryFrom` instead of `impl From`.
+pub(crate) struct ConvertTarget {
+    pub conversion: Conversion,
+    pub fallible: bool,
+    pub options: ConvertOptions,
+}
+
+/// Options following the targets in a struct-level attribute,
+/// e.g. `#[convert(try_from(A, B), collect_errors)]`.
+/// They apply to every target of that attribute.
+#[derive(Clone, Default)]
+pub(crate) struct ConvertOptions {
+    pub collect_errors: bool,
+    /// Additional generic parameters of the impl, e.g. `U` of `into(B<U>), generics(U)`
+    pub generics: Vec<GenericParam>,
+    /// Destructure the source without `..`, so that unhandled fields are compile errors
+    pub exhaustive: bool,
+    /// Fields of the source that are intentionally dropped by an `exhaustive` conversion
+    pub discard: Vec<Member>,
+    /// The base of the struct update syntax (`..base`) filling the remaining fields,
+    /// `Default::default()` for `default_rest` without a value
+    pub default_rest: Option<Expr>,
+    pub rename_rule: RenameRule,
+    /// The type of the context of `more_convert::ConvertWith`, implemented instead of `From`
+    pub context: Option<Type>,
+    /// Constructs the target with a constructor or a builder instead of a struct literal
+    pub construct: Option<TargetConstruct>,
+    /// `remote = T`, implementing `to_remote` and `from_remote` on `Self` instead of `From`
+    pub remote: bool,
+    /// The intermediate types of a conversion chained through their `From` impls,
+    /// set by `via = T` or `via(T, U)`
+    pub via: Vec<ConvertType>,
+    /// Fields of the target computed from several fields of the source, set by `merge(..)`
+    pub merge: Vec<MergeField>,
+}
+
+/// `target = [a, b] => expr` of `merge`, where `expr` is called with the fields `a` and `b`
+#[derive(Clone)]
+pub(crate) struct MergeField {
+    pub target: Ident,
+    pub sources: Vec<Member>,
+    pub func: Expr,
+}
+
+impl Parse for MergeField {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let target = input.call(Ident::parse_any)?;
+        input.parse::<Token![=]>()?;
+        let content;
+        bracketed!(content in input);
+        let sources = content.parse_terminated(Member::parse, Token![,])?;
+        input.parse::<Token![=>]>()?;
+        let func = input.parse()?;
+        Ok(Self {
+            target,
+            sources: sources.into_iter().collect(),
+            func,
+        })
+    }
+}
+
+/// `constructor = expr` or `builder`, and their `try_*` forms returning `Result`
+#[derive(Clone)]
+pub(crate) enum TargetConstruct {
+    /// The converted fields are bound to the names of the fields of the target
+    /// before `expr` is evaluated, e.g. `constructor = B::new(name, age)`
+    Constructor { expr: Expr, fallible: bool },
+    /// `Target::builder().field(value)...build()`
+    Builder { fallible: bool },
+}
+
+impl TargetConstruct {
+    pub(crate) fn is_fallible(&self) -> bool {
+        match self {
+            Self::Constructor { fallible, .. } | Self::Builder { fallible } => *fallible,
+        }
+    }
+}
+
+/// `rename_all`, `prefix` and `suffix`, renaming the fields (or the variants of enums)
+/// of the other side of a conversion
+#[derive(Clone, Default)]
+pub(crate) struct RenameRule {
+    pub rename_all: Option<Case<'static>>,
+    pub prefix: Option<LitStr>,
+    pub suffix: Option<LitStr>,
 }
 
-const EXPECT_TARGET: &str = "expected `from`, `into` or `from_into`";
+impl RenameRule {
+    /// Returns the name of the other side for `ident`
+    pub(crate) fn apply(&self, ident: &Ident) -> syn::Result<Ident> {
+        if self.rename_all.is_none() && self.prefix.is_none() && self.suffix.is_none() {
+            return Ok(ident.clone());
+        }
+
+        let mut name = unraw(ident);
+        if let Some(rename_all) = self.rename_all {
+            name = name.to_case(rename_all);
+        }
+        if let Some(prefix) = &self.prefix {
+            name = format!("{}{}", prefix.value(), name);
+        }
+        if let Some(suffix) = &self.suffix {
+            name = format!("{}{}", name, suffix.value());
+        }
+        LitStr::new(&name, ident.span())
+            .parse_with(Ident::parse_any)
+            .map_err(|_| {
+                syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "`{}` renamed by `rename_all`, `prefix` or `suffix` is not a valid identifier",
+                        name
+                    ),
+                )
+            })
+    }
+}
+
+const EXPECT_TARGET: &str =
+    "expected `from`, `into`, `from_into`, `into_ref`, `try_from`, `try_into`, `try_from_into`, `try_into_ref`, `collect_errors`, `generics`, `exhaustive`, `discard`, `default_rest`, `rename_all`, `prefix`, `suffix`, `context`, `via`, `merge`, `remote`, `constructor`, `try_constructor`, `builder`, `try_builder` or `transparent`";
+
+/// The conversions requested by a target keyword
+#[derive(Clone, Copy)]
+struct Keyword {
+    from: bool,
+    into: bool,
+    fallible: bool,
+    by_ref: bool,
+}
+
+fn parse_keyword(keyword: &str) -> Option<Keyword> {
+    let (from, into, fallible, by_ref) = match keyword {
+        "from" => (true, false, false, false),
+        "into" => (false, true, false, false),
+        "from_into" => (true, true, false, false),
+        "into_ref" => (false, true, false, true),
+        "try_from" => (true, false, true, false),
+        "try_into" => (false, true, true, false),
+        "try_from_into" => (true, true, true, false),
+        "try_into_ref" => (false, true, true, true),
+        _ => None?,
+    };
+    Some(Keyword {
+        from,
+        into,
+        fallible,
+        by_ref,
+    })
+}
+
+/// Whether the path is one of the target keywords, e.g. `from` or `try_into`
+pub(crate) fn is_target_keyword(path: &syn::Path) -> bool {
+    path.get_ident()
+        .is_some_and(|ident| parse_keyword(&ident.to_string()).is_some())
+}
+
+/// Helper function to convert a keyword and type into conversions
+fn keyword_to_conversions(keyword: &str, ty: ConvertType, self_ident: &Ident) -> Vec<Conversion> {
+    let Some(keyword) = parse_keyword(keyword) else {
+        return vec![];
+    };
 
-/// Helper function to convert a keyword and type identifier into conversions
-fn keyword_to_conversions(keyword: &str, ty: Ident, self_ident: &Ident) -> Vec<Conversion> {
-    match keyword {
-        "from" => vec![Conversion {
-            from: ty,
-            to: self_ident.clone(),
-        }],
-        "into" => vec![Conversion {
-            from: self_ident.clone(),
+    let mut conversions = Vec::new();
+    if keyword.from {
+        conversions.push(Conversion {
+            from: ty.clone(),
+            to: self_ident.into(),
+            by_ref: false,
+        });
+    }
+    if keyword.into {
+        conversions.push(Conversion {
+            from: self_ident.into(),
             to: ty,
-        }],
-        "from_into" => vec![
-            Conversion {
-                from: ty.clone(),
-                to: self_ident.clone(),
-            },
-            Conversion {
-                from: self_ident.clone(),
-                to: ty,
-            },
-        ],
-        _ => vec![],
+            by_ref: keyword.by_ref,
+        });
     }
+    conversions
 }
 
-// A single keyword argument, e.g., `from(A, B)`
-struct ConvertArg {
-    keyword: Ident,
-    types: Punctuated<Ident, Token![,]>,
+// A single argument, e.g., `from(A, B)` or `collect_errors`
+enum ConvertArg {
+    Target {
+        keyword: Ident,
+        types: Punctuated<ConvertType, Token![,]>,
+    },
+    CollectErrors(Ident),
+    Generics {
+        keyword: Ident,
+        params: Punctuated<GenericParam, Token![,]>,
+    },
+    Exhaustive(Ident),
+    /// `rename_all`, `prefix` or `suffix`
+    Rename {
+        keyword: Ident,
+        value: LitStr,
+    },
+    DefaultRest {
+        keyword: Ident,
+        base: Option<Expr>,
+    },
+    Discard {
+        keyword: Ident,
+        members: Punctuated<Member, Token![,]>,
+    },
+    Context {
+        keyword: Ident,
+        ty: Type,
+    },
+    /// `via = T` or `via(T, U, ..)`
+    Via {
+        keyword: Ident,
+        types: Vec<ConvertType>,
+    },
+    /// `merge(target = [a, b] => expr, ..)`
+    Merge {
+        keyword: Ident,
+        fields: Punctuated<MergeField, Token![,]>,
+    },
+    /// `remote = T`, the conversions between `Self` and `T` as inherent functions
+    Remote {
+        keyword: Ident,
+        ty: ConvertType,
+    },
+    /// `constructor`, `try_constructor`, `builder` or `try_builder`
+    Construct {
+        keyword: Ident,
+        construct: TargetConstruct,
+    },
+    /// Converts between a single-field struct and the type of the field
+    Transparent(Ident),
 }
 
 impl Parse for ConvertArg {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let keyword: Ident = input.parse()?;
-        let content;
-        parenthesized!(content in input);
-        let types = content.parse_terminated(Ident::parse, Token![,])?;
+        if keyword == "collect_errors" {
+            return Ok(Self::CollectErrors(keyword));
+        }
+        if keyword == "transparent" {
+            return Ok(Self::Transparent(keyword));
+        }
+        if keyword == "default_rest" {
+            let base = if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                Some(input.parse()?)
+            } else {
+                None
+            };
+            return Ok(Self::DefaultRest { keyword, base });
+        }
+        if keyword == "rename_all" || keyword == "prefix" || keyword == "suffix" {
+            input.parse::<Token![=]>()?;
+            let value = input.parse()?;
+            return Ok(Self::Rename { keyword, value });
+        }
+        if keyword == "exhaustive" {
+            return Ok(Self::Exhaustive(keyword));
+        }
+        if keyword == "context" {
+            input.parse::<Token![=]>()?;
+            let ty = input.parse()?;
+            return Ok(Self::Context { keyword, ty });
+        }
+        if keyword == "via" {
+            let types = if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                vec![input.parse()?]
+            } else {
+                let content;
+                parenthesized!(content in input);
+                content
+                    .parse_terminated(ConvertType::parse, Token![,])?
+                    .into_iter()
+                    .collect()
+            };
+            return Ok(Self::Via { keyword, types });
+        }
+        if keyword == "merge" {
+            let content;
+            parenthesized!(content in input);
+            let fields = content.parse_terminated(MergeField::parse, Token![,])?;
+            return Ok(Self::Merge { keyword, fields });
+        }
+        if keyword == "remote" {
+            input.parse::<Token![=]>()?;
+            let ty = input.parse()?;
+            return Ok(Self::Remote { keyword, ty });
+        }
+        if keyword == "constructor" || keyword == "try_constructor" {
+            input.parse::<Token![=]>()?;
+            let expr = input.parse()?;
+            let fallible = keyword == "try_constructor";
+            return Ok(Self::Construct {
+                keyword,
+                construct: TargetConstruct::Constructor { expr, fallible },
+            });
+        }
+        if keyword == "builder" || keyword == "try_builder" {
+            let fallible = keyword == "try_builder";
+            return Ok(Self::Construct {
+                keyword,
+                construct: TargetConstruct::Builder { fallible },
+            });
+        }
+        if keyword == "discard" {
+            let content;
+            parenthesized!(content in input);
+            let members = content.parse_terminated(Member::parse, Token![,])?;
+            return Ok(Self::Discard { keyword, members });
+        }
+        if keyword == "generics" {
+            let content;
+            parenthesized!(content in input);
+            let params = content.parse_terminated(GenericParam::parse, Token![,])?;
+            return Ok(Self::Generics { keyword, params });
+        }
 
-        match keyword.to_string().as_str() {
-            "from" | "into" | "from_into" => Ok(Self { keyword, types }),
-            _ => Err(syn::Error::new(keyword.span(), EXPECT_TARGET)),
+        if parse_keyword(&keyword.to_string()).is_none() {
+            return Err(syn::Error::new(keyword.span(), EXPECT_TARGET));
         }
+
+        let content;
+        parenthesized!(content in input);
+        let types = content.parse_terminated(ConvertType::parse, Token![,])?;
+        Ok(Self::Target { keyword, types })
     }
 }
 
@@ -63,25 +463,186 @@ impl Parse for ConvertArgs {
 }
 
 impl ConvertArgs {
-    pub(crate) fn into_conversions(self, self_ident: &Ident) -> syn::Result<Vec<Conversion>> {
-        let mut conversions = Vec::new();
+    /// Returns the `transparent` argument, if any
+    pub(crate) fn transparent(&self) -> Option<&Ident> {
+        self.0.iter().find_map(|arg| match arg {
+            ConvertArg::Transparent(ident) => Some(ident),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn into_targets(self, self_ident: &Ident) -> syn::Result<Vec<ConvertTarget>> {
+        let mut options = ConvertOptions::default();
+        let mut option_span = None;
+        let mut discard_span = None;
+        let mut via_span = None;
+        let mut keywords = Vec::new();
+        let mut remote: Option<(Ident, ConvertType)> = None;
         for arg in self.0 {
-            let keyword = arg.keyword.to_string();
-            for ty in arg.types {
-                let mut convs = keyword_to_conversions(&keyword, ty, self_ident);
-                if convs.is_empty() {
-                    return Err(syn::Error::new(
-                        arg.keyword.span(),
-                        format!("unexpected keyword: {}", keyword)
-                    ));
+            match arg {
+                ConvertArg::Target { keyword, types } => keywords.push((keyword, types)),
+                ConvertArg::Via { keyword, types } => {
+                    check_duplicate!(keyword.span(), via, !options.via.is_empty());
+                    options.via = types;
+                    via_span = Some(keyword.span());
+                    option_span = Some(keyword.span());
+                }
+                ConvertArg::Merge { keyword, fields } => {
+                    check_duplicate!(keyword.span(), merge, !options.merge.is_empty());
+                    options.merge = fields.into_iter().collect();
+                    option_span = Some(keyword.span());
+                }
+                ConvertArg::Remote { keyword, ty } => {
+                    check_duplicate!(keyword.span(), remote);
+                    remote = Some((keyword, ty));
+                }
+                ConvertArg::CollectErrors(ident) => {
+                    check_duplicate!(ident.span(), collect_errors, options.collect_errors);
+                    options.collect_errors = true;
+                    option_span = Some(ident.span());
+                }
+                ConvertArg::Generics { keyword, params } => {
+                    check_duplicate!(keyword.span(), generics, !options.generics.is_empty());
+                    options.generics = params.into_iter().collect();
+                    option_span = Some(keyword.span());
                 }
-                conversions.append(&mut convs);
+                ConvertArg::Exhaustive(ident) => {
+                    check_duplicate!(ident.span(), exhaustive, options.exhaustive);
+                    options.exhaustive = true;
+                    option_span = Some(ident.span());
+                }
+                ConvertArg::Discard { keyword, members } => {
+                    check_duplicate!(keyword.span(), discard, !options.discard.is_empty());
+                    options.discard = members.into_iter().collect();
+                    discard_span = Some(keyword.span());
+                    option_span = Some(keyword.span());
+                }
+                ConvertArg::DefaultRest { keyword, base } => {
+                    check_duplicate!(keyword.span(), default_rest, options.default_rest.is_some());
+                    options.default_rest =
+                        Some(base.unwrap_or_else(
+                            || syn::parse_quote! { std::default::Default::default() },
+                        ));
+                    option_span = Some(keyword.span());
+                }
+                ConvertArg::Rename { keyword, value } => {
+                    let rule = &mut options.rename_rule;
+                    if keyword == "rename_all" {
+                        check_duplicate!(keyword.span(), rename_all, rule.rename_all.is_some());
+                        rule.rename_all =
+                            Some(from_str_to_case(&value.value()).ok_or_else(|| {
+                                syn::Error::new(value.span(), "invalid rename_all")
+                            })?);
+                    } else if keyword == "prefix" {
+                        check_duplicate!(keyword.span(), prefix, rule.prefix.is_some());
+                        rule.prefix = Some(value);
+                    } else {
+                        check_duplicate!(keyword.span(), suffix, rule.suffix.is_some());
+                        rule.suffix = Some(value);
+                    }
+                    option_span = Some(keyword.span());
+                }
+                ConvertArg::Context { keyword, ty } => {
+                    check_duplicate!(keyword.span(), context, options.context.is_some());
+                    options.context = Some(ty);
+                    option_span = Some(keyword.span());
+                }
+                ConvertArg::Construct { keyword, construct } => {
+                    check_duplicate!(
+                        @__message keyword.span(),
+                        constructor,
+                        options.construct.is_some(),
+                        "chose one of `constructor`, `try_constructor`, `builder` or `try_builder`"
+                    );
+                    options.construct = Some(construct);
+                    option_span = Some(keyword.span());
+                }
+                ConvertArg::Transparent(_) => {}
             }
         }
-        conversions.sort();
-        conversions.dedup();
-        Ok(conversions)
+
+        if let (Some(span), Some(_)) = (via_span, &options.context) {
+            return Err(syn::Error::new(
+                span,
+                "`via` can not be combined with `context`",
+            ));
+        }
+
+        if let Some((keyword, ty)) = remote {
+            if let Some((target, _)) = keywords.first() {
+                return Err(syn::Error::new(
+                    target.span(),
+                    "`remote` can not be combined with other targets in the same attribute",
+                ));
+            }
+            if options.context.is_some() || !options.via.is_empty() {
+                return Err(syn::Error::new(
+                    keyword.span(),
+                    "`remote` can not be combined with `context` or `via`",
+                ));
+            }
+            // the remote type is destructured without `..`, which checks its fields
+            options.remote = true;
+            options.exhaustive = true;
+            return Ok(keyword_to_conversions("from_into", ty, self_ident)
+                .into_iter()
+                .map(|conversion| ConvertTarget {
+                    conversion,
+                    fallible: false,
+                    options: options.clone(),
+                })
+                .collect());
+        }
+
+        if let (Some(span), true) = (option_span, keywords.is_empty()) {
+            return Err(syn::Error::new(
+                span,
+                "options require a target in the same attribute, e.g. `#[convert(try_from(A), collect_errors)]`",
+            ));
+        }
+
+        if let (Some(span), false) = (discard_span, options.exhaustive) {
+            return Err(syn::Error::new(
+                span,
+                "`discard` requires `exhaustive`, e.g. `#[convert(from(A), exhaustive, discard(a))]`",
+            ));
+        }
+
+        let mut targets = Vec::new();
+        for (keyword, types) in keywords {
+            let keyword = keyword.to_string();
+            let fallible = parse_keyword(&keyword).is_some_and(|keyword| keyword.fallible);
+            for ty in types {
+                for conversion in keyword_to_conversions(&keyword, ty, self_ident) {
+                    targets.push(ConvertTarget {
+                        conversion,
+                        fallible,
+                        options: options.clone(),
+                    });
+                }
+            }
+        }
+        Ok(targets)
+    }
+}
+
+/// Collects the targets of every struct-level `#[convert(...)]` attribute.
+///
+/// # Errors
+///
+/// Returns an error if the same conversion is specified more than once.
+pub(crate) fn merge_targets(mut targets: Vec<ConvertTarget>) -> syn::Result<Vec<ConvertTarget>> {
+    targets.sort_by(|a, b| a.conversion.cmp(&b.conversion));
+    for pair in targets.windows(2) {
+        if pair[0].conversion == pair[1].conversion {
+            let conversion = &pair[1].conversion;
+            return Err(syn::Error::new(
+                conversion.from.span(),
+                format!("duplicate conversion `{}`", conversion),
+            ));
+        }
     }
+    Ok(targets)
 }
 
 // For parsing field-level attributes like `#[convert(from(A), ...)]`
@@ -94,13 +655,18 @@ pub(crate) fn parse_field_conversion_meta(
         _ => return Ok(None),
     };
 
-    let Some(keyword) = list.path.get_ident() else {
+    // other lists are options of the field, e.g. `split(..)`
+    let Some(keyword) = list
+        .path
+        .get_ident()
+        .filter(|_| is_target_keyword(&list.path))
+    else {
         return Ok(None);
     };
 
-    let idents = list.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+    let types = list.parse_args_with(Punctuated::<ConvertType, Token![,]>::parse_terminated)?;
 
-    let conversions = idents
+    let conversions = types
         .into_iter()
         .flat_map(|ty| keyword_to_conversions(&keyword.to_string(), ty, self_ident))
         .collect::<Vec<_>>();
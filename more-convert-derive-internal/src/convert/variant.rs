@@ -0,0 +1,243 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{spanned::Spanned, Ident, Member, Variant};
+
+use crate::unraw;
+
+use super::{
+    body::{BodyGenerator, ErrorMode, FieldToken},
+    error_mode,
+    field::{member_name, ConvertAttrs, ConvertField},
+    field_arg::ConvertFieldMap,
+    generics::ConvertGenerics,
+    renamed_member,
+    target::{ConvertTarget, RenameRule},
+    target_ctor,
+};
+
+pub(crate) struct ConvertVariant<'a> {
+    pub ident: &'a Ident,
+    pub fields: Vec<ConvertField<'a>>,
+    /// Only `ignore` and `rename` are allowed on variants
+    pub attrs: ConvertAttrs,
+}
+
+impl<'a> ConvertVariant<'a> {
+    pub(crate) fn from_variant(variant: &'a Variant, self_ident: &Ident) -> syn::Result<Self> {
+        let attrs = ConvertAttrs::from_attrs(&variant.attrs, self_ident)?;
+        if std::iter::once(&attrs.all)
+            .chain(attrs.target.values())
+            .any(|arg| arg.map.is_some())
+        {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                "maps are not supported on variants.\n\
+                \n\
+                Help: Put `map`, `map_field` or `try_map_field` on the fields of the variant.",
+            ));
+        }
+        if std::iter::once(&attrs.all)
+            .chain(attrs.target.values())
+            .any(|arg| matches!(arg.rename, Some(Member::Unnamed(_))))
+        {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                "`index` is not supported on variants, use `rename`",
+            ));
+        }
+        if attrs.all.flatten {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                "`flatten` is not supported on variants",
+            ));
+        }
+
+        let fields = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| ConvertField::from_field(field, i, self_ident))
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        if let Some(field) = fields.iter().find(|field| field.attrs.all.flatten) {
+            return Err(syn::Error::new(
+                field.member.span(),
+                "`flatten` is not supported on the fields of variants",
+            ));
+        }
+        for field in &fields {
+            if let Some(path) = std::iter::once(&field.attrs.all)
+                .chain(field.attrs.target.values())
+                .find_map(|arg| arg.path.as_ref())
+            {
+                return Err(syn::Error::new(
+                    path.span,
+                    "`source` and `target_path` are not supported on the fields of variants",
+                ));
+            }
+            if std::iter::once(&field.attrs.all)
+                .chain(field.attrs.target.values())
+                .any(|arg| arg.split.is_some())
+            {
+                return Err(syn::Error::new(
+                    field.member.span(),
+                    "`split` is not supported on the fields of variants",
+                ));
+            }
+            if std::iter::once(&field.attrs.all)
+                .chain(field.attrs.target.values())
+                .any(|arg| matches!(arg.map, Some(ConvertFieldMap::StructFn(_))))
+            {
+                return Err(syn::Error::new(
+                    field.ty.span(),
+                    "`map_struct` is not supported on the fields of variants, \
+                    because the enum is moved by the match",
+                ));
+            }
+        }
+
+        Ok(Self {
+            ident: &variant.ident,
+            fields,
+            attrs,
+        })
+    }
+}
+
+/// Generates a `match` converting each variant to the variant of the same name.
+///
+/// Variants ignored in an `into` conversion make it fallible,
+/// returning `more_convert::IgnoredVariantError` as the source of the error.
+pub(crate) fn gen_enum_body(
+    target: &ConvertTarget,
+    variants: &[ConvertVariant],
+    self_ident: &Ident,
+    generics: &mut ConvertGenerics,
+) -> syn::Result<(ErrorMode, TokenStream)> {
+    let conversion = &target.conversion;
+    let from_ty = &conversion.from;
+    let into_ty = &conversion.to;
+    let is_from = into_ty.is_ident(self_ident);
+    // generic arguments are not allowed in patterns, they are inferred
+    let from_path = from_ty.to_pattern_path();
+
+    let fallible = variants.iter().any(|variant| {
+        let arg = variant.attrs.get_arg_for_conversion(conversion);
+        if arg.ignore {
+            return !is_from;
+        }
+        variant.fields.iter().any(|field| {
+            field
+                .get_arg_for_conversion(conversion)
+                .is_fallible(is_from)
+        })
+    });
+    let mode = error_mode(target, fallible);
+    let generator = BodyGenerator {
+        from: from_ty,
+        into: into_ty,
+        mode,
+        rest: None,
+        construct: None,
+    };
+
+    let mut arms = Vec::with_capacity(variants.len());
+    for variant in variants {
+        let arg = variant.attrs.get_arg_for_conversion(conversion);
+        // the rename rule of the target applies to the variants
+        let other = match &arg.rename {
+            Some(Member::Named(rename)) => rename.clone(),
+            _ => target.options.rename_rule.apply(variant.ident)?,
+        };
+        let (source_variant, target_variant) = if is_from {
+            (&other, variant.ident)
+        } else {
+            (variant.ident, &other)
+        };
+
+        if arg.ignore {
+            if !is_from {
+                let name = unraw(source_variant);
+                let error = generator.gen_error(
+                    &name,
+                    quote! { more_convert::IgnoredVariantError { variant: #name } },
+                );
+                arms.push(quote! {
+                    #from_path::#source_variant { .. } => {
+                        return std::result::Result::Err(#error);
+                    }
+                });
+            }
+            continue;
+        }
+
+        let mut bindings = Vec::with_capacity(variant.fields.len());
+        let mut field_tokens = Vec::with_capacity(variant.fields.len());
+        for field in &variant.fields {
+            let arg = field.get_arg_for_conversion(conversion);
+            if arg.ignore {
+                continue;
+            }
+
+            let renamed =
+                renamed_member(&field.member, arg.rename.as_ref(), &RenameRule::default())?;
+            let (source_member, target_member) = if is_from {
+                (renamed, field.member.clone())
+            } else {
+                (field.member.clone(), renamed)
+            };
+
+            // `map` can refer to the fields of the variant by these names
+            let local = match &field.member {
+                Member::Named(ident) => ident.clone(),
+                Member::Unnamed(index) => format_ident!("_{}", index.index),
+            };
+            if arg.map.is_none() {
+                generics.add_field_bound(
+                    &arg.shape(field.ty),
+                    is_from,
+                    mode.is_fallible(),
+                    arg.access(conversion.by_ref),
+                    arg.leaf,
+                );
+            }
+            let source = format!("{}.{}", unraw(source_variant), member_name(&source_member));
+            // the bindings are references in `into_ref`
+            let (token, is_result) = arg.to_token(
+                field.ty,
+                &local.to_token_stream(),
+                &source,
+                mode.is_fallible(),
+                target,
+                is_from,
+            );
+
+            field_tokens.push(FieldToken::converted(
+                target_member.to_token_stream(),
+                source,
+                token,
+                is_result,
+            ));
+            bindings.push(match &source_member {
+                Member::Named(ident) if *ident == local => quote! { #local },
+                _ => quote! { #source_member: #local },
+            });
+        }
+
+        let ctor = target_ctor(target);
+        let construct = generator.gen_construct(&quote! { #ctor::#target_variant }, &field_tokens);
+        arms.push(quote! {
+            #[allow(unused_variables)]
+            #from_path::#source_variant { #( #bindings, )* .. } => #construct,
+        });
+    }
+
+    Ok((
+        mode,
+        quote! {
+            match value {
+                #( #arms )*
+            }
+        },
+    ))
+}
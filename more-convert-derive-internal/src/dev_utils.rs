@@ -1,24 +1,19 @@
 use convert_case::Case;
 use syn::{meta::ParseNestedMeta, Type};
 
-/// Validates that the input is a struct with named fields.
+/// Validates that the input is a struct (named, tuple or unit).
 ///
 /// # Errors
 ///
-/// Returns an error if the input is not a struct with named fields.
-pub(crate) fn require_named_field_struct(
-    input: &syn::DeriveInput,
-) -> syn::Result<&syn::FieldsNamed> {
+/// Returns an error if the input is not a struct.
+pub(crate) fn require_struct(input: &syn::DeriveInput) -> syn::Result<&syn::Fields> {
     match &input.data {
-        syn::Data::Struct(syn::DataStruct {
-            fields: syn::Fields::Named(fields),
-            ..
-        }) => Ok(fields),
+        syn::Data::Struct(syn::DataStruct { fields, .. }) => Ok(fields),
         _ => Err(syn::Error::new_spanned(
             input,
-            "this macro only supports structs with named fields.\n\
+            "this macro only supports structs.\n\
             \n\
-            Example: `struct Foo { field: Type }`",
+            Example: `struct Foo { field: Type }` or `struct Foo(Type)`",
         )),
     }
 }
@@ -40,48 +35,6 @@ pub(crate) fn require_enum(
     }
 }
 
-/// Checks if the given type is a `Vec<T>`.
-///
-/// # Examples
-///
-/// ```ignore
-/// use syn::{parse_quote, Type};
-/// let ty: Type = parse_quote!(Vec<u32>);
-/// assert!(is_vec(&ty));
-/// ```
-pub(crate) fn is_vec(ty: &Type) -> bool {
-    is_type_eq_ident(ty, "Vec")
-}
-
-/// Checks if the given type is an `Option<T>`.
-///
-/// # Examples
-///
-/// ```ignore
-/// use syn::{parse_quote, Type};
-/// let ty: Type = parse_quote!(Option<String>);
-/// assert!(is_option(&ty));
-/// ```
-pub(crate) fn is_option(ty: &Type) -> bool {
-    is_type_eq_ident(ty, "Option")
-}
-
-/// Checks if the last segment of a type path matches the given identifier.
-///
-/// # Examples
-///
-/// ```ignore
-/// use syn::{parse_quote, Type};
-/// let ty: Type = parse_quote!(std::vec::Vec<u32>);
-/// assert!(is_type_eq_ident(&ty, "Vec"));
-/// ```
-pub(crate) fn is_type_eq_ident<S: AsRef<str>>(ty: &Type, s: S) -> bool {
-    match get_last_path_segment(ty) {
-        Some(seg) => seg.ident == s,
-        _ => false,
-    }
-}
-
 /// Extracts the last segment of a type path, if it exists.
 ///
 /// # Examples
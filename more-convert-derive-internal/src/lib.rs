@@ -10,7 +10,7 @@ mod enum_repr;
 pub use enum_repr::derive_enum_repr;
 
 mod convert;
-pub use convert::derive_convert;
+pub use convert::{convert_flatten, convert_fn, derive_convert};
 
 mod variant_name;
 pub use variant_name::derive_variant_name;
@@ -151,7 +151,7 @@ pub fn derive_enum_repr(input: proc_macro::TokenStream) -> proc_macro::TokenStre
     use_internal!(more_convert_derive_internal::derive_enum_repr, input)
 }
 
-/// Automatically implements [`std::convert::From`] on structs.
+/// Automatically implements [`std::convert::From`] or [`std::convert::TryFrom`] on structs and enums.
 ///
 /// # Who uses it:
 ///   - When you are using the architectural
@@ -161,10 +161,93 @@ pub fn derive_enum_repr(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 ///   - into: `impl From<#self> for #into_struct { /* auto gen */}`
 ///   - from: `impl From<#from_struct> for #self { /* auto gen */}`
 ///   - from_into: impl from and into
+///   - into_ref: `impl From<&#self> for #into_struct { /* auto gen */}`
+///     > fields are cloned and converted with `Into`, or converted with `From<&T>` with `by_ref`
+///   - try_into: `impl TryFrom<#self> for #into_struct { /* auto gen */}`
+///   - try_from: `impl TryFrom<#from_struct> for #self { /* auto gen */}`
+///   - try_from_into: impl try_from and try_into
+///   - try_into_ref: `impl TryFrom<&#self> for #into_struct { /* auto gen */}`
+///     > targets can be any type, e.g. `into(dto::User, Page<UserDto>)`,
+///     > and are matched with the filters of fields by their tokens
+///     > fields use `TryInto` by default, and errors are propagated with `?`
+///     > the error type is [`more_convert::ConvertError`], which records the path of the failed field
+///   - collect_errors: (option of the targets in the same attribute)
+///     collect the errors of every field in [`more_convert::ConvertErrors`]
+///     instead of returning the first one
+///   - generics: (option of the targets in the same attribute)
+///     additional generic parameters of the impl, e.g. `#[convert(into(B<U>), generics(U))]`
+///     > the generic arguments of the target are mapped to the parameters of `Self` by position,
+///     > and a target is used as written, e.g. `into(WrapperDto<T>)` for the same parameter
+///     > bounds such as `T: Into<U>` are inferred from the fields
+///   - exhaustive: (option of the targets in the same attribute, struct only)
+///     destructure the source without `..`, so a new field of the source is a compile error
+///   - discard: (with `exhaustive`) the fields of the source that are intentionally dropped,
+///     e.g. `#[convert(from(B), exhaustive, discard(internal_id))]`
+///     > the fields read as `value.field` by `map`, `try_map` and `generate` are handled,
+///     > the others read by maps (e.g. through `map_struct`) must be discarded too
+///   - default_rest: (option of the targets in the same attribute, struct only)
+///     fill the remaining fields of the target with `..Default::default()`,
+///     or with `..expr` for `default_rest = expr`
+///     > for `from`, the fields of `Self` ignored in the conversion are filled from `Self::default()`
+///   - rename_all, prefix, suffix: (options of the targets in the same attribute)
+///     rename the fields of the other side (the variants for enums),
+///     e.g. `#[convert(into(B), rename_all = "camelCase", prefix = "db_")]`
+///     > Possible values of rename_all: same as `VariantName`
+///     > `rename` of the field overrides them
+///   - context: (option of the targets in the same attribute)
+///     implement [`more_convert::ConvertWith`] (or [`more_convert::TryConvertWith`]) instead of `From`,
+///     e.g. `#[convert(into(UserDto), context = Config)]`, called as `user.convert_with(&config)`
+///     > maps can read the context as `ctx: &Config`
+///     > the fields are converted with `ConvertWith`, so nested types deriving it get the context
+///   - via: (option of the targets in the same attribute)
+///     chain the conversion through the `From` impls of intermediate types,
+///     e.g. `#[convert(into(ApiDto), via = Domain)]` for `DbRow -> Domain -> ApiDto`
+///     > `via(A, B)` chains through several types, and the fields are not converted
+///     > `try_*` targets chain `TryFrom` impls, the errors are reported at the name of the type
+///     > a missing impl is reported at the type of the hop, e.g. `Domain: From<DbRow>` at `via = Domain`
+///   - remote: a mirror of a type of another crate, like serde's `remote`,
+///     e.g. `#[convert(remote = other::Config)]`
+///     implements `Self::to_remote(Self) -> other::Config` and `Self::from_remote(other::Config) -> Self`
+///     instead of `From`, so the remote type can be converted with the attributes of the fields
+///     > the functions return `Result` when a field is fallible
+///     > the remote type is destructured like `exhaustive`, so its fields must match those of `Self`
+///     > (the fields read as `value.field` by `map` are handled)
+///     > a remote type can not be combined with other targets in the same attribute
+///   - constructor, builder: (options of the targets in the same attribute, struct only)
+///     construct the target without a struct literal, e.g. for types with private fields
+///     - constructor: `#[convert(into(B), constructor = B::new(name, age))]`
+///       > the converted fields are bound to the names of the fields of the target
+///       > (`_0`, `_1`, .. for tuple structs) before the expression is evaluated,
+///       > and a field not used by the expression must be ignored for the target
+///     - builder: `#[convert(into(B), builder)]` calls `B::builder().name(name).age(age).build()`
+///     - try_constructor, try_builder: the constructor or `build()` returns `Result`,
+///       which makes the conversion fallible
+///     > they can not be combined with `default_rest`
+///   - merge: (option of the targets in the same attribute, struct only)
+///     compute a field of the target from several fields of the source,
+///     e.g. `#[convert(into(B), merge(full_name = [first, last] => join_name))]`
+///     calls `join_name(first, last)` for `full_name` of `B`
+///     > the merged fields are moved into the function (references in `into_ref`),
+///     > and can not be converted individually
+///     > for `from`, the fields are those of the source, and the result is the field of `Self`
+///   - transparent: (single-field struct only)
+///     `impl From<#field_type> for #self` and `impl From<#self> for #field_type`
 ///
 /// # Struct Attribute #[generate]:
 ///   - example `#[generate(B(is_negative = value.sample.is_negative()))]`
 ///     generate is used to generate the field value of the target struct
+///   - into: `#[generate(into(B, C), is_negative = value.sample.is_negative(), ..)]`
+///     same as above for several targets and fields
+///   - from: `#[generate(from(B), full_name = format!("{} {}", value.first, value.last))]`
+///     generate the field value of `Self`, where `value` is the `B` being converted
+///     > the generated fields must be ignored in the conversion, e.g. `#[convert(from(B), ignore)]`
+///     > `into` and `from` choose the direction of a `from_into` target
+///
+/// # Variant Attribute: (enum only, variants are matched by name)
+///   - filter of target: same as field attribute
+///   - ignore: ignore this variant, converting it with `into` returns an error
+///     (makes the conversion fallible, see [`more_convert::IgnoredVariantError`])
+///   - rename: rename this variant
 ///
 /// # Field Attribute:
 ///   - filter of target: (option, default apply all)
@@ -172,11 +255,70 @@ pub fn derive_enum_repr(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 ///     - apply priority: from and into > from_into > all
 ///   - ignore: ignore this field
 ///   - rename: rename this field
+///   - name: map this field to the named field of the other side (same as rename)
+///   - index: map this field to the tuple field of the other side
+///     > tuple fields map to the tuple fields of the same position by default
+///   - source: (`from` only) read this field from a nested field of the source,
+///     e.g. `#[convert(from(B), source = "meta.created_at")]` reads `value.meta.created_at`
+///   - target_path: (`into` only) write this field to a nested field of the target,
+///     e.g. `#[convert(into(B), target_path = "meta.created_at")]`
+///     > the field is assigned after the target is constructed,
+///     > so the parent field must be filled otherwise, e.g. with `default_rest`
+///   - by_ref: convert this field with `From<&T>` instead of `Clone` in `into_ref`
+///   - required, unwrap_or_default, unwrap_or: the other side is an `Option` of this field,
+///     e.g. `#[convert(from(Proto), required)]` for `Option<u64>` of `Proto` and `u64` of `Self`
+///     > `from` reads the content of the `Option`: `None` is an error with `required`
+///     > (makes the conversion fallible, see [`more_convert::MissingFieldError`]),
+///     > `Default::default()` with `unwrap_or_default`, and `expr` with `unwrap_or = expr`
+///     > `into` wraps the value in `Some`
+///     > the content is converted like the field, including the elements of containers
+///   - parse, display: convert this field with `str::parse` or `ToString` instead of `Into`,
+///     e.g. `#[convert(from(Dto), parse)]` for `String` of `Dto` and `Uuid` of `Self`
+///     > they apply to the elements of containers, and compose with `required`
+///     > `parse` makes the conversion fallible, the error is the `FromStr::Err` of the type
+///   - cast, checked, saturating, round: convert this numeric field instead of `Into`,
+///     e.g. `#[convert(cast)]` for `u64` of `Self` and `u32` of the other side
+///     - cast: `as` (truncates)
+///     - checked: `TryFrom`, makes the conversion fallible
+///     - saturating: clamp to the range of the target, see [`more_convert::SaturatingFrom`]
+///     - round: round floats to integers, see [`more_convert::RoundFrom`]
+///     > they apply to the elements of containers like `parse` and `display`
+///   - container: the shape of the type of this field for the default conversion,
+///     e.g. `#[convert(container = "Vec<_>")]` for a type alias of `Vec`
+///     > `_` is converted as a whole, so `container = "_"` disables the walk of containers
+///     > `Rc` and `Arc` are walked only when they are written in the hint
+///   - flatten: (no filter of target) the type of this field is a struct deriving `Convert`,
+///     whose fields are spread into the fields of the other side, or gathered back from them
+///     > the attributes of the nested fields apply, and flattens can be nested
+///     > the rename rule of the target applies to the nested fields too
+///     > ignored nested fields are filled with `Default::default()` in `from`
+///     > the nested struct must have named fields and no generics,
+///     > and be defined in the same crate (its derive defines a crate-local macro named after it,
+///     > which is imported along with the struct, e.g. by `use domain::Address;`)
+///   - split: (`into` only) compute several fields of the target from this field,
+///     e.g. `#[convert(into(B), split(first = first_name(name), last = last_name(name)))]`
+///     > the expressions read a reference to the field by its name (`_0`, `_1`, .. for tuple structs),
+///     > and the field itself is not converted
+///     > it can not be combined with maps, renames or the options of the default conversion
 ///   - group of map: map this field (Choose one of these)
-///     > default: `#field_name.into()`
+///     > default: `#field_name.into()`, applied to the elements of containers:
+///     > `Option`, `Result`, `Vec` and the other std collections, `HashMap`, `BTreeMap`,
+///     > `Box` and arrays, nested in any way
+///     > `Rc` and `Arc` are converted as a whole, so they keep sharing their content,
+///     > `container = "Arc<_>"` converts the content (cloned if shared) into a new `Arc`
+///     > in fallible conversions the errors of maps and `Result` are boxed
 ///     - map: replace expr
-///     - map_field: Process and pass field data
-///     - map_struct: Create data from struct references
+///     - map_field: Process and pass field data (a reference in `into_ref`),
+///       a path of a function or a closure
+///     - map_struct: Create data from struct references (not supported in enum variants)
+///     - try_map: replace expr returning `Result`
+///     - try_map_field: Process and pass field data, returning `Result`
+///     - with: a module with `into_target(field)` and `from_target(field)`, like serde's `with`
+///       (the field is cloned in `into_ref`, or passed as a reference with `by_ref`)
+///     - try_with: a module with `try_into_target(field)` and `try_from_target(field)`, returning `Result`
+///     > `try_map`, `try_map_field` and `try_with` make the conversion fallible
+///     > `map`, `try_map`, `map_struct`, `generate` and `split` are evaluated before any field is moved,
+///     > so they can borrow every field of `value`
 ///
 /// # Examples
 ///
@@ -290,6 +432,296 @@ pub fn derive_enum_repr(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 /// assert_eq!(b.map_struct, "3");
 /// ```
 ///
+/// ## with
+///
+/// ```rust
+/// # mod more_convert {
+/// #   pub use more_convert_derive::Convert;
+/// # }
+/// use more_convert::Convert;
+///
+/// mod cents {
+///     pub fn into_target(value: u64) -> f64 {
+///         value as f64 / 100.0
+///     }
+///
+///     pub fn from_target(value: f64) -> u64 {
+///         (value * 100.0).round() as u64
+///     }
+/// }
+///
+/// #[derive(Convert)]
+/// #[convert(from_into(B))]
+/// pub struct A {
+///     #[convert(with = cents)]
+///     pub price: u64,
+/// }
+///
+/// pub struct B {
+///     pub price: f64,
+/// }
+///
+/// let b: B = A { price: 150 }.into();
+/// assert_eq!(b.price, 1.5);
+///
+/// let a: A = b.into();
+/// assert_eq!(a.price, 150);
+/// ```
+///
+/// ## try_from
+///
+/// ```rust
+/// # mod more_convert {
+/// #   pub use more_convert_derive::Convert;
+/// #   include!("../../more-convert/src/convert.rs");
+/// # }
+/// use more_convert::Convert;
+///
+/// #[derive(Convert, Debug)]
+/// #[convert(try_from(B))]
+/// pub struct A {
+///     // auto try_into
+///     pub narrow: u8,
+///     #[convert(try_map = value.parsed.parse())]
+///     pub parsed: u16,
+/// }
+///
+/// pub struct B {
+///     narrow: u32,
+///     parsed: String,
+/// }
+///
+/// let b = B { narrow: 1, parsed: "2".to_string() };
+/// let a: A = b.try_into().unwrap();
+/// assert_eq!(a.narrow, 1u8);
+/// assert_eq!(a.parsed, 2u16);
+///
+/// let b = B { narrow: 256, parsed: "2".to_string() };
+/// let error = A::try_from(b).unwrap_err();
+/// // the path of the source field that failed
+/// assert_eq!(error.path, "narrow");
+/// ```
+///
+/// ## collect_errors
+///
+/// ```rust
+/// # mod more_convert {
+/// #   pub use more_convert_derive::Convert;
+/// #   include!("../../more-convert/src/convert.rs");
+/// # }
+/// use more_convert::Convert;
+///
+/// #[derive(Convert, Debug)]
+/// #[convert(try_from(B), collect_errors)]
+/// pub struct A {
+///     pub first: u8,
+///     pub second: u8,
+/// }
+///
+/// pub struct B {
+///     first: u32,
+///     second: u32,
+/// }
+///
+/// let b = B { first: 256, second: 256 };
+/// let errors = A::try_from(b).unwrap_err();
+/// let paths: Vec<_> = errors.into_iter().map(|e| e.path).collect();
+/// assert_eq!(paths, ["first", "second"]);
+/// ```
+///
+/// ## Enum
+///
+/// ```rust
+/// # mod more_convert {
+/// #   pub use more_convert_derive::Convert;
+/// # }
+/// use more_convert::Convert;
+///
+/// #[derive(Convert)]
+/// #[convert(into(B))]
+/// pub enum A {
+///     Unit,
+///     #[convert(rename = "Renamed")]
+///     Tuple(u8),
+///     Struct {
+///         // fields of the variant can be used in `map`
+///         #[convert(map = sample.to_string())]
+///         sample: u8,
+///     },
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum B {
+///     Unit,
+///     Renamed(u16),
+///     Struct { sample: String },
+/// }
+///
+/// assert_eq!(B::from(A::Unit), B::Unit);
+/// assert_eq!(B::from(A::Tuple(1)), B::Renamed(1));
+/// assert_eq!(B::from(A::Struct { sample: 2 }), B::Struct { sample: "2".to_string() });
+/// ```
+///
+/// ## Tuple struct
+///
+/// ```rust
+/// # mod more_convert {
+/// #   pub use more_convert_derive::Convert;
+/// # }
+/// use more_convert::Convert;
+///
+/// // `From<u64> for UserId` and `From<UserId> for u64`
+/// #[derive(Convert, Debug, PartialEq)]
+/// #[convert(transparent)]
+/// pub struct UserId(u64);
+///
+/// #[derive(Convert, Debug, PartialEq)]
+/// #[convert(into(Named))]
+/// pub struct Tuple(
+///     #[convert(name = "id")] u64,
+///     #[convert(name = "name")] String,
+/// );
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct Named {
+///     id: UserId,
+///     name: String,
+/// }
+///
+/// assert_eq!(UserId::from(1), UserId(1));
+/// assert_eq!(u64::from(UserId(1)), 1);
+///
+/// let named: Named = Tuple(1, "name".to_string()).into();
+/// assert_eq!(named, Named { id: UserId(1), name: "name".to_string() });
+/// ```
+///
+/// ## Generics
+///
+/// ```rust
+/// # mod more_convert {
+/// #   pub use more_convert_derive::Convert;
+/// # }
+/// use more_convert::Convert;
+///
+/// // impl<T, U> From<Wrapper<T>> for WrapperDto<U> where T: Into<U>
+/// #[derive(Convert)]
+/// #[convert(into(WrapperDto<U>), generics(U))]
+/// pub struct Wrapper<T> {
+///     pub items: Vec<T>,
+/// }
+///
+/// pub struct WrapperDto<T> {
+///     pub items: Vec<T>,
+/// }
+///
+/// let dto: WrapperDto<u16> = Wrapper { items: vec![1u8] }.into();
+/// assert_eq!(dto.items, vec![1u16]);
+/// ```
+///
+/// ## into_ref
+///
+/// ```rust
+/// # mod more_convert {
+/// #   pub use more_convert_derive::Convert;
+/// # }
+/// use more_convert::Convert;
+///
+/// #[derive(Convert)]
+/// #[convert(into_ref(B))]
+/// pub struct A {
+///     pub name: String,
+///     #[convert(map_field = str::len)]
+///     pub len: String,
+/// }
+///
+/// pub struct B {
+///     pub name: String,
+///     pub len: usize,
+/// }
+///
+/// let a = A { name: "name".to_string(), len: "four".to_string() };
+/// let b = B::from(&a);
+///
+/// assert_eq!(b.name, a.name);
+/// assert_eq!(b.len, 4);
+/// ```
+///
+/// ## default_rest
+///
+/// ```rust
+/// # mod more_convert {
+/// #   pub use more_convert_derive::Convert;
+/// # }
+/// use more_convert::Convert;
+///
+/// #[derive(Convert)]
+/// #[convert(into(B), default_rest)]
+/// pub struct A {
+///     pub name: String,
+/// }
+///
+/// #[derive(Default)]
+/// pub struct B {
+///     pub name: String,
+///     pub note: Option<String>,
+///     pub count: u32,
+/// }
+///
+/// let b: B = A { name: "name".to_string() }.into();
+/// assert_eq!(b.name, "name");
+/// assert_eq!(b.note, None);
+/// ```
+///
+/// ## constructor
+///
+/// ```rust
+/// # mod more_convert {
+/// #   pub use more_convert_derive::Convert;
+/// # }
+/// use more_convert::Convert;
+///
+/// #[derive(Convert)]
+/// #[convert(into(B), constructor = B::new(name))]
+/// pub struct A {
+///     pub name: String,
+/// }
+///
+/// pub struct B {
+///     name: String,
+/// }
+///
+/// impl B {
+///     pub fn new(name: String) -> Self {
+///         Self { name: name.to_uppercase() }
+///     }
+/// }
+///
+/// let b: B = A { name: "name".to_string() }.into();
+/// assert_eq!(b.name, "NAME");
+/// ```
+///
+/// ## exhaustive
+///
+/// ```rust,compile_fail,E0027
+/// # mod more_convert {
+/// #   pub use more_convert_derive::Convert;
+/// # }
+/// use more_convert::Convert;
+///
+/// // error: pattern does not mention field `email`
+/// #[derive(Convert)]
+/// #[convert(from(B), exhaustive, discard(internal_id))]
+/// pub struct A {
+///     pub name: String,
+/// }
+///
+/// pub struct B {
+///     pub name: String,
+///     pub internal_id: u64,
+///     pub email: String,
+/// }
+/// ```
+///
 /// ## from_into and filter
 ///
 /// ```rust
@@ -318,6 +750,52 @@ pub fn derive_enum_repr(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 /// assert_eq!(a.hey, 0u16);
 /// ```
 ///
+/// ## flatten
+///
+/// ```rust
+/// # mod more_convert {
+/// #   pub use more_convert_derive::{Convert, __convert_flatten};
+/// #   pub trait __Flatten<const FIELD: usize> {
+/// #       type Field;
+/// #   }
+/// # }
+/// use more_convert::Convert;
+///
+/// #[derive(Convert)]
+/// pub struct Address {
+///     pub street: String,
+///     #[convert(rename = "zip_code")]
+///     pub zip: String,
+/// }
+///
+/// #[derive(Convert)]
+/// #[convert(from_into(UserDto))]
+/// pub struct User {
+///     pub name: String,
+///     #[convert(flatten)]
+///     pub address: Address,
+/// }
+///
+/// pub struct UserDto {
+///     pub name: String,
+///     pub street: String,
+///     pub zip_code: String,
+/// }
+///
+/// let dto: UserDto = User {
+///     name: "alice".to_string(),
+///     address: Address {
+///         street: "main".to_string(),
+///         zip: "100".to_string(),
+///     },
+/// }
+/// .into();
+/// assert_eq!(dto.zip_code, "100");
+///
+/// let user: User = dto.into();
+/// assert_eq!(user.address.street, "main");
+/// ```
+///
 /// ## #[genearate]
 ///
 /// ```rust
@@ -343,6 +821,27 @@ pub fn derive_enum_repr(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 ///
 /// assert_eq!(b.sample, -1);
 /// assert!(b.is_negative);
+///
+/// #[derive(Convert)]
+/// #[convert(from_into(UserDto))]
+/// #[generate(from(UserDto), is_active = !value.deleted)]
+/// #[generate(into(UserDto), deleted = !value.is_active)]
+/// pub struct User {
+///     pub name: String,
+///     #[convert(ignore)]
+///     pub is_active: bool,
+/// }
+///
+/// pub struct UserDto {
+///     pub name: String,
+///     pub deleted: bool,
+/// }
+///
+/// let user: User = UserDto { name: "a".to_string(), deleted: true }.into();
+/// assert!(!user.is_active);
+///
+/// let dto: UserDto = user.into();
+/// assert!(dto.deleted);
 /// ```
 ///
 #[proc_macro_derive(Convert, attributes(convert, generate))]
@@ -350,6 +849,92 @@ pub fn derive_convert(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     use_internal!(more_convert_derive_internal::derive_convert, input)
 }
 
+/// Converts between two types without implementing a trait,
+/// e.g. when neither type is defined in the crate, like two generated crates.
+///
+/// `convert!(Source => Target { fields })` is a closure converting `Source` into `Target`,
+/// which returns `Result<Target, more_convert::ConvertError>` if a field is fallible.
+/// `convert!(pub fn name: Source => Target { fields })` declares the same as a function,
+/// which can be written once at the module level and reused.
+///
+/// # Fields:
+///   - the fields of the target, with an optional type, e.g. `tags: Vec<Tag>`
+///     > the type is the shape of the default conversion, a field without a type is converted
+///     > as a whole with `Into`
+///   - the attributes of the fields are those of [`Convert`] without the filter of target,
+///     as for a struct deriving `#[convert(from(Source))]`
+///     > `map` and `map_struct` read the source as `value`
+///   - the options taking a value can follow the name instead of the type,
+///     e.g. `name: rename(full_name)`, `created_at: source(meta.created_at)`
+///     or `tags: map(value.tags.iter().map(|tag| tag.name.clone()).collect())`
+///     > a closure of `map` or `try_map` is called with the source field like `map_field`,
+///     > e.g. `tags: map(|tags| tags.len())`
+///     > `rename`, `source`, `map`, `map_field`, `map_struct`, `try_map`, `try_map_field`,
+///     > `with`, `try_with` and `unwrap_or`, combined with the attribute of the field
+///   - `flatten` is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// # mod more_convert {
+/// #   pub use more_convert_derive::convert;
+/// #   include!("../../more-convert/src/convert.rs");
+/// # }
+/// mod proto {
+///     pub struct User {
+///         pub id: u32,
+///         pub full_name: String,
+///         pub tags: Vec<&'static str>,
+///         pub age: String,
+///     }
+/// }
+///
+/// mod api {
+///     pub struct User {
+///         pub id: u64,
+///         pub name: String,
+///         pub tags: Vec<String>,
+///         pub age: u8,
+///     }
+/// }
+///
+/// let to_api = more_convert::convert!(proto::User => api::User {
+///     id,
+///     name: rename(full_name),
+///     tags: map(|tags| tags.into_iter().map(String::from).collect()),
+///     #[convert(parse)]
+///     age,
+/// });
+///
+/// let user = to_api(proto::User {
+///     id: 1,
+///     full_name: "Jane Doe".to_string(),
+///     tags: vec!["admin"],
+///     age: "30".to_string(),
+/// })
+/// .unwrap();
+/// assert_eq!(user.id, 1);
+/// assert_eq!(user.name, "Jane Doe");
+/// assert_eq!(user.tags, vec!["admin".to_string()]);
+/// assert_eq!(user.age, 30);
+/// ```
+#[proc_macro]
+pub fn convert(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    more_convert_derive_internal::convert_fn(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Continues the derive of `Convert` on a struct with `flatten` fields.
+/// Called by the hidden macros generated by `Convert`, not intended to be used directly.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __convert_flatten(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    more_convert_derive_internal::convert_flatten(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// Automatically implements [`more_convert::VariantName`] on enum
 ///
 /// # Where to use:
@@ -0,0 +1,95 @@
+/// Conversion between numeric types clamping the value to the range of `Self`,
+/// used by `#[convert(saturating)]` of `Convert`.
+///
+/// `NaN` converts to `0` for integers.
+pub trait SaturatingFrom<T> {
+    fn saturating_from(value: T) -> Self;
+}
+
+/// Conversion of floats to integers rounding half away from zero, used by `#[convert(round)]`
+/// of `Convert`.
+///
+/// The rounded value is clamped to the range of `Self`, and `NaN` converts to `0`.
+pub trait RoundFrom<T> {
+    fn round_from(value: T) -> Self;
+}
+
+macro_rules! impl_saturating_int {
+    ($($from:ty),*) => {
+        $(
+            impl_saturating_int!(
+                @to $from;
+                i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+            );
+        )*
+    };
+    (@to $from:ty; $($to:ty),*) => {
+        $(
+            impl SaturatingFrom<$from> for $to {
+                #[inline]
+                #[allow(unused_comparisons)]
+                fn saturating_from(value: $from) -> Self {
+                    <$to>::try_from(value).unwrap_or(if value < 0 {
+                        <$to>::MIN
+                    } else {
+                        <$to>::MAX
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_float {
+    ($($from:ty),*) => {
+        $(
+            impl_float!(
+                @to $from;
+                i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+            );
+
+            impl SaturatingFrom<$from> for f64 {
+                #[inline]
+                fn saturating_from(value: $from) -> Self {
+                    value as f64
+                }
+            }
+
+            impl SaturatingFrom<$from> for f32 {
+                #[inline]
+                fn saturating_from(value: $from) -> Self {
+                    (value as f64).clamp(f32::MIN as f64, f32::MAX as f64) as f32
+                }
+            }
+        )*
+    };
+    (@to $from:ty; $($to:ty),*) => {
+        $(
+            // `as` saturates from floats to integers
+            impl SaturatingFrom<$from> for $to {
+                #[inline]
+                fn saturating_from(value: $from) -> Self {
+                    value as $to
+                }
+            }
+
+            impl RoundFrom<$from> for $to {
+                #[inline]
+                fn round_from(value: $from) -> Self {
+                    value.round() as $to
+                }
+            }
+
+            impl SaturatingFrom<$to> for $from {
+                #[inline]
+                fn saturating_from(value: $to) -> Self {
+                    value as $from
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
@@ -0,0 +1,41 @@
+/// Conversion of `self` into `Target` with a context, e.g. a locale or a lookup table.
+///
+/// `Convert` implements it with `#[convert(into(B), context = Ctx)]`,
+/// where `map` can use the context as `ctx`.
+/// Every `T: Into<Target>` implements it ignoring the context,
+/// so the fields of a derived conversion pass the context to the nested conversions.
+pub trait ConvertWith<Target, Ctx: ?Sized> {
+    fn convert_with(self, ctx: &Ctx) -> Target;
+}
+
+impl<T, Target, Ctx: ?Sized> ConvertWith<Target, Ctx> for T
+where
+    T: Into<Target>,
+{
+    #[inline]
+    fn convert_with(self, _: &Ctx) -> Target {
+        self.into()
+    }
+}
+
+/// The fallible version of [`ConvertWith`], implemented by `Convert` for fallible conversions
+/// with a context, e.g. `#[convert(try_into(B), context = Ctx)]`.
+///
+/// Every `T: TryInto<Target>` implements it ignoring the context.
+pub trait TryConvertWith<Target, Ctx: ?Sized> {
+    type Error;
+
+    fn try_convert_with(self, ctx: &Ctx) -> Result<Target, Self::Error>;
+}
+
+impl<T, Target, Ctx: ?Sized> TryConvertWith<Target, Ctx> for T
+where
+    T: TryInto<Target>,
+{
+    type Error = T::Error;
+
+    #[inline]
+    fn try_convert_with(self, _: &Ctx) -> Result<Target, Self::Error> {
+        self.try_into()
+    }
+}
@@ -0,0 +1,124 @@
+use std::error::Error;
+
+type BoxError = Box<dyn Error + Send + Sync>;
+
+/// The error of a fallible conversion generated by `Convert`.
+///
+/// `path` is the dotted path of the source field that failed,
+/// including the fields of nested converted structs (e.g. `address.zip`).
+#[derive(Debug, thiserror::Error)]
+#[error("Failed to convert {source_type} to {target_type} at `{path}`: {source}")]
+pub struct ConvertError {
+    pub path: String,
+    pub source_type: &'static str,
+    pub target_type: &'static str,
+    pub source: BoxError,
+}
+
+impl ConvertError {
+    /// Creates an error for `field`.
+    ///
+    /// If `error` is itself a [`ConvertError`] of a nested conversion,
+    /// its path is prefixed with `field` and its source is kept.
+    pub fn new(
+        source_type: &'static str,
+        target_type: &'static str,
+        field: &str,
+        error: impl Into<BoxError>,
+    ) -> Self {
+        match error.into().downcast::<ConvertError>() {
+            Ok(nested) => nested.prefixed(source_type, target_type, field),
+            Err(source) => Self {
+                path: field.to_string(),
+                source_type,
+                target_type,
+                source,
+            },
+        }
+    }
+
+    fn prefixed(self, source_type: &'static str, target_type: &'static str, field: &str) -> Self {
+        Self {
+            path: format!("{}.{}", field, self.path),
+            source_type,
+            target_type,
+            source: self.source,
+        }
+    }
+}
+
+/// The source of [`ConvertError`] when an enum variant ignored by `Convert` is converted.
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+#[error("variant {variant} is ignored")]
+pub struct IgnoredVariantError {
+    pub variant: &'static str,
+}
+
+/// The source of [`ConvertError`] when a field marked `required` by `Convert` is `None`.
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+#[error("field {field} is missing")]
+pub struct MissingFieldError {
+    pub field: &'static str,
+}
+
+/// The error of a fallible conversion generated by `Convert` with `collect_errors`.
+///
+/// Contains an error for every field that failed, instead of only the first one.
+#[derive(Debug, Default)]
+pub struct ConvertErrors {
+    pub errors: Vec<ConvertError>,
+}
+
+impl ConvertErrors {
+    /// Records the failure of `field`.
+    ///
+    /// Nested [`ConvertErrors`] and [`ConvertError`] are flattened
+    /// with their paths prefixed by `field`.
+    pub fn push(
+        &mut self,
+        source_type: &'static str,
+        target_type: &'static str,
+        field: &str,
+        error: impl Into<BoxError>,
+    ) {
+        match error.into().downcast::<ConvertErrors>() {
+            Ok(nested) => self.errors.extend(
+                nested
+                    .errors
+                    .into_iter()
+                    .map(|error| error.prefixed(source_type, target_type, field)),
+            ),
+            Err(error) => {
+                self.errors
+                    .push(ConvertError::new(source_type, target_type, field, error))
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl std::fmt::Display for ConvertErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i != 0 {
+                f.write_str("\n")?;
+            }
+            std::fmt::Display::fmt(error, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ConvertErrors {}
+
+impl IntoIterator for ConvertErrors {
+    type Item = ConvertError;
+    type IntoIter = std::vec::IntoIter<ConvertError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
@@ -7,11 +7,31 @@
     clippy::print_stderr
 )]
 
+pub use more_convert_derive::convert;
 pub use more_convert_derive::Convert;
 pub use more_convert_derive::EnumArray;
 pub use more_convert_derive::EnumRepr;
 pub use more_convert_derive::VariantName;
 
+#[doc(hidden)]
+pub use more_convert_derive::__convert_flatten;
+
+/// The type of the `flatten` field at `FIELD` of a struct deriving `Convert`,
+/// which names the nested flattened structs outside of their module.
+#[doc(hidden)]
+pub trait __Flatten<const FIELD: usize> {
+    type Field;
+}
+
+mod cast;
+pub use cast::*;
+
+mod context;
+pub use context::*;
+
+mod convert;
+pub use convert::*;
+
 mod enum_repr;
 pub use enum_repr::*;
 
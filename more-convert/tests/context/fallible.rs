@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use more_convert::{Convert, TryConvertWith};
+
+pub struct Lookup {
+    pub countries: HashMap<String, u16>,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(AddressDto), context = Lookup)]
+pub struct Address {
+    #[convert(try_map = ctx.countries.get(&value.country).copied().ok_or("unknown country"))]
+    pub country: u16,
+    pub zip: u16,
+}
+
+pub struct AddressDto {
+    pub country: String,
+    pub zip: u32,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(UserDto), context = Lookup)]
+pub struct User {
+    pub name: String,
+    // the context is passed to the nested fallible conversions
+    pub addresses: Vec<Address>,
+}
+
+pub struct UserDto {
+    pub name: String,
+    pub addresses: Vec<AddressDto>,
+}
+
+#[test]
+pub fn main() {
+    let ctx = Lookup {
+        countries: HashMap::from([("JP".to_string(), 392)]),
+    };
+    let dto = UserDto {
+        name: "name".to_string(),
+        addresses: vec![AddressDto {
+            country: "JP".to_string(),
+            zip: 1000001,
+        }],
+    };
+    let error = TryConvertWith::<User, _>::try_convert_with(dto, &ctx).unwrap_err();
+    assert_eq!(error.path, "addresses.zip");
+
+    let dto = UserDto {
+        name: "name".to_string(),
+        addresses: vec![AddressDto {
+            country: "JP".to_string(),
+            zip: 100,
+        }],
+    };
+    let user: User = dto.try_convert_with(&ctx).unwrap();
+    assert_eq!(
+        user,
+        User {
+            name: "name".to_string(),
+            addresses: vec![Address {
+                country: 392,
+                zip: 100
+            }],
+        }
+    );
+
+    let dto = UserDto {
+        name: "name".to_string(),
+        addresses: vec![AddressDto {
+            country: "US".to_string(),
+            zip: 100,
+        }],
+    };
+    let error = TryConvertWith::<User, _>::try_convert_with(dto, &ctx).unwrap_err();
+    assert_eq!(error.path, "addresses.country");
+    assert_eq!(error.source.to_string(), "unknown country");
+}
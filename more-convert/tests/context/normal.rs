@@ -0,0 +1,94 @@
+use more_convert::{Convert, ConvertWith};
+
+pub struct Context {
+    pub base_url: String,
+}
+
+#[derive(Convert)]
+#[convert(into(UserDto), context = Context)]
+pub struct User {
+    pub id: u32,
+    #[convert(map = format!("{}/users/{}", ctx.base_url, value.id))]
+    pub url: String,
+    // the context is passed to the nested conversions
+    pub avatar: Image,
+    pub images: Vec<Image>,
+}
+
+#[derive(Convert)]
+#[convert(into(ImageDto), context = Context)]
+pub struct Image {
+    #[convert(map = format!("{}/{}", ctx.base_url, value.path))]
+    pub path: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UserDto {
+    pub id: u64,
+    pub url: String,
+    pub avatar: ImageDto,
+    pub images: Vec<ImageDto>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ImageDto {
+    pub path: String,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(StatusDto), context = str)]
+pub enum Status {
+    Active,
+    #[convert(rename = "Suspended")]
+    Banned {
+        #[convert(map_field = str::to_string)]
+        reason: String,
+    },
+}
+
+pub enum StatusDto {
+    Active,
+    Suspended { reason: &'static str },
+}
+
+#[test]
+pub fn main() {
+    let ctx = Context {
+        base_url: "https://example.com".to_string(),
+    };
+    let user = User {
+        id: 1,
+        url: String::new(),
+        avatar: Image {
+            path: "avatar.png".to_string(),
+        },
+        images: vec![Image {
+            path: "image.png".to_string(),
+        }],
+    };
+
+    let dto: UserDto = user.convert_with(&ctx);
+    assert_eq!(
+        dto,
+        UserDto {
+            id: 1,
+            url: "https://example.com/users/1".to_string(),
+            avatar: ImageDto {
+                path: "https://example.com/avatar.png".to_string(),
+            },
+            images: vec![ImageDto {
+                path: "https://example.com/image.png".to_string(),
+            }],
+        }
+    );
+
+    let status: Status = StatusDto::Suspended { reason: "spam" }.convert_with("unused");
+    assert_eq!(
+        status,
+        Status::Banned {
+            reason: "spam".to_string()
+        }
+    );
+    let status: Status = StatusDto::Active.convert_with("unused");
+    assert_eq!(status, Status::Active);
+}
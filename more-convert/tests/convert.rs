@@ -1,10 +1,23 @@
 #![cfg(test)]
 
+pub mod context;
+pub mod convert_macro;
 pub mod enum_array;
+pub mod enum_convert;
 pub mod enum_repr;
+pub mod flatten;
 pub mod from;
 pub mod from_into;
+pub mod generics;
 pub mod into;
+pub mod into_ref;
+pub mod remote;
+pub mod rename_all;
+pub mod try_from;
+pub mod try_from_into;
+pub mod try_into;
+pub mod tuple_struct;
+pub mod type_path;
 pub mod variant_name;
 
 #[cfg(feature = "utils")]
@@ -0,0 +1,38 @@
+use more_convert::convert;
+
+pub struct Row {
+    pub title: String,
+    pub tags: Vec<String>,
+    pub score: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Summary {
+    pub title: usize,
+    pub tags: usize,
+    pub score: u8,
+}
+
+#[test]
+pub fn main() {
+    // a closure of `map` is called with the source field
+    let to_summary = convert!(Row => Summary {
+        title: map_field(|t: String| t.len()),
+        tags: map(|t| t.len()),
+        score: try_map(|s| s.parse::<u8>()),
+    });
+
+    let summary = to_summary(Row {
+        title: "title".to_string(),
+        tags: vec!["a".to_string(), "b".to_string()],
+        score: "5".to_string(),
+    });
+    assert_eq!(
+        summary.unwrap(),
+        Summary {
+            title: 5,
+            tags: 2,
+            score: 5,
+        }
+    );
+}
@@ -0,0 +1,50 @@
+use std::error::Error;
+
+use more_convert::{convert, ConvertError, MissingFieldError};
+
+pub struct Request {
+    pub id: Option<String>,
+    pub amount: i64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Payment {
+    pub id: u64,
+    pub amount: u32,
+}
+
+pub fn to_payment(request: Request) -> Result<Payment, ConvertError> {
+    convert!(Request => Payment {
+        #[convert(required, parse)]
+        id,
+        #[convert(checked)]
+        amount,
+    })(request)
+}
+
+#[test]
+pub fn main() {
+    let payment = to_payment(Request {
+        id: Some("1".to_string()),
+        amount: 100,
+    });
+    assert_eq!(payment.unwrap(), Payment { id: 1, amount: 100 });
+
+    let error = to_payment(Request {
+        id: None,
+        amount: 100,
+    })
+    .unwrap_err();
+    assert_eq!(error.path, "id");
+    assert_eq!(
+        error.source().unwrap().downcast_ref::<MissingFieldError>(),
+        Some(&MissingFieldError { field: "id" })
+    );
+
+    let error = to_payment(Request {
+        id: Some("1".to_string()),
+        amount: -1,
+    })
+    .unwrap_err();
+    assert_eq!(error.path, "amount");
+}
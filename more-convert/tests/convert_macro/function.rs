@@ -0,0 +1,61 @@
+use more_convert::{convert, ConvertError};
+
+pub struct Row {
+    pub id: i64,
+    pub title: String,
+    pub score: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Post {
+    pub id: i64,
+    pub name: String,
+    pub score: u8,
+}
+
+// declared once and reused like any function
+convert!(pub fn to_post: Row => Post {
+    id,
+    name: rename(title),
+    #[convert(parse)]
+    score,
+});
+
+convert!(fn to_title: Row => Title {
+    title: map(value.title.to_uppercase()),
+    // the shorthand is combined with the attribute
+    #[convert(parse)]
+    points: rename(score),
+});
+
+#[derive(Debug, PartialEq)]
+pub struct Title {
+    pub title: String,
+    pub points: u8,
+}
+
+#[test]
+pub fn main() {
+    let row = || Row {
+        id: 1,
+        title: "title".to_string(),
+        score: "5".to_string(),
+    };
+
+    let post: Result<Post, ConvertError> = to_post(row());
+    assert_eq!(
+        post.unwrap(),
+        Post {
+            id: 1,
+            name: "title".to_string(),
+            score: 5,
+        }
+    );
+    assert_eq!(
+        to_title(row()).unwrap(),
+        Title {
+            title: "TITLE".to_string(),
+            points: 5,
+        }
+    );
+}
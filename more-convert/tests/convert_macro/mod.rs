@@ -0,0 +1,4 @@
+pub mod closure;
+pub mod fallible;
+pub mod function;
+pub mod normal;
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use more_convert::convert;
+
+// types of other crates, which can't derive `Convert`
+pub mod proto {
+    use std::collections::HashMap;
+
+    pub struct User {
+        pub id: u32,
+        pub full_name: String,
+        pub tags: Vec<Tag>,
+        pub scores: HashMap<String, u8>,
+        pub meta: Meta,
+        pub nickname: Option<String>,
+    }
+
+    pub struct Meta {
+        pub created_at: u32,
+    }
+
+    pub struct Tag {
+        pub name: String,
+    }
+}
+
+pub mod api {
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq)]
+    pub struct User {
+        pub id: u64,
+        pub name: String,
+        pub tag_names: Vec<String>,
+        pub scores: HashMap<String, u32>,
+        pub created_at: u64,
+        pub nickname: String,
+        pub tag_count: usize,
+    }
+}
+
+pub fn to_api(user: proto::User) -> api::User {
+    convert!(proto::User => api::User {
+        id,
+        name: rename(full_name),
+        #[convert(rename = "tags", map_field = tag_names)]
+        tag_names,
+        scores: HashMap<String, u32>,
+        created_at: source(meta.created_at),
+        #[convert(unwrap_or_default)]
+        nickname,
+        tag_count: map(value.tags.iter().filter(|tag| !tag.name.is_empty()).count()),
+    })(user)
+}
+
+fn tag_names(tags: Vec<proto::Tag>) -> Vec<String> {
+    tags.into_iter().map(|tag| tag.name).collect()
+}
+
+#[test]
+pub fn main() {
+    let user = proto::User {
+        id: 1,
+        full_name: "Jane Doe".to_string(),
+        tags: vec![proto::Tag {
+            name: "admin".to_string(),
+        }],
+        scores: HashMap::from([("math".to_string(), 90)]),
+        meta: proto::Meta { created_at: 100 },
+        nickname: None,
+    };
+
+    assert_eq!(
+        to_api(user),
+        api::User {
+            id: 1,
+            name: "Jane Doe".to_string(),
+            tag_names: vec!["admin".to_string()],
+            scores: HashMap::from([("math".to_string(), 90)]),
+            created_at: 100,
+            nickname: String::new(),
+            tag_count: 1,
+        }
+    );
+
+    // the closure can be passed to `map`
+    let names: Vec<String> = vec![proto::Tag {
+        name: "a".to_string(),
+    }]
+    .into_iter()
+    .map(convert!(proto::Tag => Name { name }))
+    .map(|name| name.name)
+    .collect();
+    assert_eq!(names, vec!["a".to_string()]);
+}
+
+pub struct Name {
+    pub name: String,
+}
@@ -0,0 +1,52 @@
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into(B))]
+pub enum A {
+    Unit,
+    Tuple(u8, #[convert(map_field = to_string)] u8),
+    Struct {
+        normal: u8,
+        #[convert(rename = "renamed")]
+        hey: Option<u8>,
+        // fields of the variant can be used in `map`
+        #[convert(map = normal + 1)]
+        plus_one: u8,
+        #[convert(ignore)]
+        ignored: u8,
+    },
+}
+
+fn to_string(value: u8) -> String {
+    value.to_string()
+}
+
+#[derive(Debug, PartialEq)]
+pub enum B {
+    Unit,
+    Tuple(u16, String),
+    Struct {
+        normal: u16,
+        renamed: Option<u16>,
+        plus_one: u8,
+    },
+}
+
+#[test]
+pub fn main() {
+    assert_eq!(B::from(A::Unit), B::Unit);
+    assert_eq!(B::from(A::Tuple(1, 2)), B::Tuple(1, "2".to_string()));
+    assert_eq!(
+        B::from(A::Struct {
+            normal: 1,
+            hey: Some(2),
+            plus_one: 0,
+            ignored: 3,
+        }),
+        B::Struct {
+            normal: 1,
+            renamed: Some(2),
+            plus_one: 2,
+        }
+    );
+}
@@ -0,0 +1,46 @@
+use more_convert::{Convert, IgnoredVariantError};
+
+// ignoring a variant makes `into` fallible
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from_into(B))]
+pub enum A {
+    First,
+    #[convert(ignore)]
+    Second,
+    Third(
+        #[convert(from(B), try_map_field = parse)]
+        #[convert(into(B), map_field = to_string)]
+        u8,
+    ),
+}
+
+fn parse(value: String) -> Result<u8, std::num::ParseIntError> {
+    value.parse()
+}
+
+fn to_string(value: u8) -> String {
+    value.to_string()
+}
+
+#[derive(Debug, PartialEq)]
+pub enum B {
+    First,
+    Third(String),
+}
+
+#[test]
+pub fn main() {
+    assert_eq!(B::try_from(A::First).unwrap(), B::First);
+    assert_eq!(B::try_from(A::Third(1)).unwrap(), B::Third("1".to_string()));
+
+    let error = B::try_from(A::Second).unwrap_err();
+    assert_eq!(error.path, "Second");
+    assert_eq!(
+        error.source.downcast_ref::<IgnoredVariantError>(),
+        Some(&IgnoredVariantError { variant: "Second" })
+    );
+
+    assert_eq!(A::try_from(B::Third("2".to_string())).unwrap(), A::Third(2));
+    let error = A::try_from(B::Third("a".to_string())).unwrap_err();
+    assert_eq!(error.path, "Third.0");
+}
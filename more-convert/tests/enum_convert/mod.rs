@@ -0,0 +1,3 @@
+pub mod fields;
+pub mod ignore;
+pub mod normal;
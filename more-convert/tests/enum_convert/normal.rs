@@ -0,0 +1,32 @@
+use more_convert::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from_into(DomainStatus))]
+pub enum ApiStatus {
+    Active,
+    Inactive,
+    #[convert(rename = "Deleted")]
+    Removed,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DomainStatus {
+    Active,
+    Inactive,
+    Deleted,
+}
+
+#[test]
+pub fn main() {
+    assert_eq!(DomainStatus::from(ApiStatus::Active), DomainStatus::Active);
+    assert_eq!(
+        DomainStatus::from(ApiStatus::Inactive),
+        DomainStatus::Inactive
+    );
+    assert_eq!(
+        DomainStatus::from(ApiStatus::Removed),
+        DomainStatus::Deleted
+    );
+
+    assert_eq!(ApiStatus::from(DomainStatus::Deleted), ApiStatus::Removed);
+}
@@ -0,0 +1,76 @@
+use more_convert::Convert;
+
+use domain::{Address, Geo};
+
+mod domain {
+    use more_convert::Convert;
+
+    // the flatten macro of `Geo` is imported along with it
+    pub use geo::Geo;
+
+    mod geo {
+        use more_convert::Convert;
+
+        #[derive(Convert, Debug, PartialEq)]
+        pub struct Geo {
+            pub lat: i32,
+            pub lng: i32,
+        }
+    }
+
+    #[derive(Convert, Debug, PartialEq)]
+    pub struct Address {
+        pub city: String,
+        #[convert(flatten)]
+        pub geo: Geo,
+    }
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from_into(Row))]
+pub struct User {
+    pub name: String,
+    #[convert(flatten)]
+    pub address: Address,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Row {
+    pub name: String,
+    pub city: String,
+    pub lat: i32,
+    pub lng: i32,
+}
+
+#[test]
+pub fn main() {
+    let user = User {
+        name: "alice".to_string(),
+        address: Address {
+            city: "tokyo".to_string(),
+            geo: Geo { lat: 35, lng: 139 },
+        },
+    };
+
+    let row: Row = user.into();
+    assert_eq!(
+        row,
+        Row {
+            name: "alice".to_string(),
+            city: "tokyo".to_string(),
+            lat: 35,
+            lng: 139,
+        }
+    );
+
+    assert_eq!(
+        User::from(row),
+        User {
+            name: "alice".to_string(),
+            address: Address {
+                city: "tokyo".to_string(),
+                geo: Geo { lat: 35, lng: 139 },
+            },
+        }
+    );
+}
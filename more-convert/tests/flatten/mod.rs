@@ -0,0 +1,3 @@
+pub mod import;
+pub mod nested;
+pub mod normal;
@@ -0,0 +1,89 @@
+use more_convert::Convert;
+
+mod domain {
+    use more_convert::Convert;
+
+    pub use geo::Geo;
+
+    // the derive of `User` names `geo::Geo` through `more_convert::__Flatten` of `Address`
+    mod geo {
+        use more_convert::Convert;
+
+        #[derive(Convert, Debug, PartialEq)]
+        pub struct Geo {
+            pub lat: i32,
+            pub lng: i32,
+        }
+    }
+
+    #[derive(Convert, Debug, PartialEq)]
+    pub struct Address {
+        pub city: String,
+        // flattens compose
+        #[convert(flatten)]
+        pub geo: geo::Geo,
+        #[convert(ignore)]
+        pub verified: bool,
+    }
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into_ref(Row), try_from(Row), prefix = "user_")]
+pub struct User {
+    pub name: String,
+    #[convert(flatten)]
+    pub address: domain::Address,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Row {
+    pub user_name: String,
+    pub user_city: String,
+    pub user_lat: i64,
+    pub user_lng: i64,
+}
+
+#[test]
+pub fn main() {
+    let user = User {
+        name: "alice".to_string(),
+        address: domain::Address {
+            city: "tokyo".to_string(),
+            geo: domain::Geo { lat: 35, lng: 139 },
+            verified: true,
+        },
+    };
+
+    let row = Row::from(&user);
+    assert_eq!(
+        row,
+        Row {
+            user_name: "alice".to_string(),
+            user_city: "tokyo".to_string(),
+            user_lat: 35,
+            user_lng: 139,
+        }
+    );
+
+    // the ignored field of the flattened struct is filled with `Default::default()`
+    assert_eq!(
+        User::try_from(row).unwrap(),
+        User {
+            name: "alice".to_string(),
+            address: domain::Address {
+                city: "tokyo".to_string(),
+                geo: domain::Geo { lat: 35, lng: 139 },
+                verified: false,
+            },
+        }
+    );
+
+    let error = User::try_from(Row {
+        user_name: "bob".to_string(),
+        user_city: "osaka".to_string(),
+        user_lat: i64::MAX,
+        user_lng: 135,
+    })
+    .unwrap_err();
+    assert_eq!(error.path, "user_lat");
+}
@@ -0,0 +1,56 @@
+use more_convert::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+pub struct Address {
+    pub street: String,
+    #[convert(rename = "zip_code")]
+    pub zip: String,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from_into(UserDto))]
+pub struct User {
+    pub name: String,
+    #[convert(flatten)]
+    pub address: Address,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UserDto {
+    pub name: String,
+    pub street: String,
+    pub zip_code: String,
+}
+
+#[test]
+pub fn main() {
+    let user = User {
+        name: "alice".to_string(),
+        address: Address {
+            street: "main".to_string(),
+            zip: "100".to_string(),
+        },
+    };
+
+    let dto: UserDto = user.into();
+    assert_eq!(
+        dto,
+        UserDto {
+            name: "alice".to_string(),
+            street: "main".to_string(),
+            zip_code: "100".to_string(),
+        }
+    );
+
+    let user: User = dto.into();
+    assert_eq!(
+        user,
+        User {
+            name: "alice".to_string(),
+            address: Address {
+                street: "main".to_string(),
+                zip: "100".to_string(),
+            },
+        }
+    );
+}
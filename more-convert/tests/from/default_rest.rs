@@ -0,0 +1,38 @@
+use more_convert::Convert;
+
+// the fields of `Self` without a source are filled from `Self::default()`
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(B), default_rest)]
+pub struct A {
+    pub sample: u8,
+    #[convert(ignore)]
+    pub hey: u16,
+    #[convert(ignore)]
+    pub list: Vec<u8>,
+}
+
+impl Default for A {
+    fn default() -> Self {
+        Self {
+            sample: 0,
+            hey: 1,
+            list: vec![2],
+        }
+    }
+}
+
+pub struct B {
+    sample: u8,
+}
+
+#[test]
+pub fn main() {
+    assert_eq!(
+        A::from(B { sample: 3 }),
+        A {
+            sample: 3,
+            hey: 1,
+            list: vec![2],
+        }
+    );
+}
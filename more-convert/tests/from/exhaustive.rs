@@ -0,0 +1,51 @@
+use more_convert::Convert;
+
+// `age` is read as `value.age` by `map`, so it is handled
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(B), exhaustive, discard(internal_id))]
+pub struct A {
+    pub id: u16,
+    #[convert(rename = "user_name")]
+    pub name: String,
+    #[convert(map = value.age as u32)]
+    pub age: u32,
+}
+
+pub struct B {
+    pub id: u8,
+    pub user_name: String,
+    pub age: u8,
+    pub internal_id: u64,
+}
+
+// the ignored fields of `Self` are handled
+#[derive(Convert)]
+#[convert(into(B), exhaustive)]
+pub struct C {
+    pub id: u8,
+    pub user_name: String,
+    pub age: u8,
+    pub internal_id: u64,
+    #[convert(ignore)]
+    pub cache: Vec<u8>,
+}
+
+#[test]
+pub fn main() {
+    let c = C {
+        id: 1,
+        user_name: "name".to_string(),
+        age: 20,
+        internal_id: 2,
+        cache: vec![],
+    };
+
+    assert_eq!(
+        A::from(B::from(c)),
+        A {
+            id: 1,
+            name: "name".to_string(),
+            age: 20,
+        }
+    );
+}
@@ -0,0 +1,58 @@
+use more_convert::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(B, C))]
+#[generate(from(B, C), full_name = format!("{} {}", value.first_name, value.last_name))]
+#[generate(from(B), initial = value.first_name.chars().next().unwrap_or_default())]
+#[generate(from(C), initial = '-')]
+pub struct A {
+    pub first_name: String,
+    pub last_name: String,
+    #[convert(ignore)]
+    pub full_name: String,
+    #[convert(ignore)]
+    pub initial: char,
+}
+
+pub struct B {
+    pub first_name: String,
+    pub last_name: String,
+}
+
+pub struct C {
+    pub first_name: String,
+    pub last_name: String,
+}
+
+#[test]
+pub fn main() {
+    let b = B {
+        first_name: "Jane".to_string(),
+        last_name: "Doe".to_string(),
+    };
+    let a: A = b.into();
+    assert_eq!(
+        a,
+        A {
+            first_name: "Jane".to_string(),
+            last_name: "Doe".to_string(),
+            full_name: "Jane Doe".to_string(),
+            initial: 'J',
+        }
+    );
+
+    let c = C {
+        first_name: "John".to_string(),
+        last_name: "Smith".to_string(),
+    };
+    let a: A = c.into();
+    assert_eq!(
+        a,
+        A {
+            first_name: "John".to_string(),
+            last_name: "Smith".to_string(),
+            full_name: "John Smith".to_string(),
+            initial: '-',
+        }
+    );
+}
@@ -1,5 +1,9 @@
+pub mod default_rest;
+pub mod exhaustive;
+pub mod generate;
 pub mod ignore;
 pub mod map;
 pub mod multi;
 pub mod normal;
 pub mod rename;
+pub mod source_path;
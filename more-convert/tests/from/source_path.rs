@@ -0,0 +1,42 @@
+use more_convert::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(B))]
+pub struct A {
+    pub id: u32,
+    #[convert(source = "meta.created_at")]
+    pub created_at: u64,
+    #[convert(source = "meta.tags.0")]
+    pub first_tag: String,
+}
+
+pub struct B {
+    pub id: u16,
+    pub meta: Meta,
+}
+
+pub struct Meta {
+    pub created_at: u32,
+    pub tags: (String, String),
+}
+
+#[test]
+pub fn main() {
+    let b = B {
+        id: 1,
+        meta: Meta {
+            created_at: 100,
+            tags: ("new".to_string(), "hot".to_string()),
+        },
+    };
+
+    let a: A = b.into();
+    assert_eq!(
+        a,
+        A {
+            id: 1,
+            created_at: 100,
+            first_tag: "new".to_string(),
+        }
+    );
+}
@@ -13,6 +13,27 @@ pub struct B {
     sample: u8,
 }
 
+// both directions of `from_into` compute their own fields
+#[derive(Convert)]
+#[convert(from_into(UserDto))]
+#[generate(from(UserDto), full_name = format!("{} {}", value.first_name, value.last_name), is_active = !value.deleted)]
+#[generate(into(UserDto), deleted = !value.is_active)]
+pub struct User {
+    pub first_name: String,
+    pub last_name: String,
+    #[convert(ignore)]
+    pub full_name: String,
+    #[convert(ignore)]
+    pub is_active: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UserDto {
+    first_name: String,
+    last_name: String,
+    deleted: bool,
+}
+
 #[test]
 pub fn main() {
     let a = A { sample: 1 };
@@ -20,4 +41,23 @@ pub fn main() {
     let b: B = a.into();
 
     assert_eq!(b, B { sample: 201 });
+
+    let user: User = UserDto {
+        first_name: "Jane".to_string(),
+        last_name: "Doe".to_string(),
+        deleted: false,
+    }
+    .into();
+    assert_eq!(user.full_name, "Jane Doe");
+    assert!(user.is_active);
+
+    let dto: UserDto = user.into();
+    assert_eq!(
+        dto,
+        UserDto {
+            first_name: "Jane".to_string(),
+            last_name: "Doe".to_string(),
+            deleted: false,
+        }
+    );
 }
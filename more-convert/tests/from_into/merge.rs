@@ -0,0 +1,67 @@
+use more_convert::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(Contact), merge(full_name = [first_name, last_name] => join_name))]
+pub struct User {
+    pub first_name: String,
+    pub last_name: String,
+    pub age: u8,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(User))]
+pub struct Contact {
+    #[convert(split(
+        first_name = full_name.split(' ').next().unwrap_or_default().to_string(),
+        last_name = full_name.split(' ').nth(1).unwrap_or_default().to_string(),
+    ))]
+    pub full_name: String,
+    pub age: u8,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(User), merge(greeting = [first_name, last_name] => greet))]
+pub struct Greeting {
+    pub greeting: String,
+    pub age: u8,
+}
+
+fn join_name(first_name: String, last_name: String) -> String {
+    format!("{} {}", first_name, last_name)
+}
+
+fn greet(first_name: String, last_name: String) -> String {
+    format!("Hello, {} {}!", first_name, last_name)
+}
+
+fn user() -> User {
+    User {
+        first_name: "Ada".to_string(),
+        last_name: "Lovelace".to_string(),
+        age: 36,
+    }
+}
+
+#[test]
+pub fn main() {
+    let contact: Contact = user().into();
+    assert_eq!(
+        contact,
+        Contact {
+            full_name: "Ada Lovelace".to_string(),
+            age: 36,
+        }
+    );
+
+    let back: User = contact.into();
+    assert_eq!(back, user());
+
+    let greeting: Greeting = user().into();
+    assert_eq!(
+        greeting,
+        Greeting {
+            greeting: "Hello, Ada Lovelace!".to_string(),
+            age: 36,
+        }
+    );
+}
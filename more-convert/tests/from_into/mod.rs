@@ -1,6 +1,10 @@
 pub mod generate;
 pub mod ignore;
 pub mod map;
+pub mod merge;
 pub mod multi;
 pub mod normal;
+pub mod optional;
+pub mod parse;
 pub mod rename;
+pub mod with;
@@ -0,0 +1,67 @@
+use std::error::Error;
+
+use more_convert::{Convert, MissingFieldError};
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from_into(Proto))]
+pub struct User {
+    // `from` is fallible, `into` wraps the value in `Some`
+    #[convert(required)]
+    pub id: u64,
+    #[convert(unwrap_or_default)]
+    pub tags: Vec<String>,
+    #[convert(from(Proto), unwrap_or = 20)]
+    #[convert(into(Proto), unwrap_or_default)]
+    pub age: u16,
+    pub nickname: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Proto {
+    pub id: Option<u64>,
+    pub tags: Option<Vec<String>>,
+    pub age: Option<u16>,
+    pub nickname: Option<String>,
+}
+
+#[test]
+pub fn main() {
+    let proto = Proto {
+        id: Some(1),
+        tags: None,
+        age: None,
+        nickname: None,
+    };
+    let user = User::try_from(proto).unwrap();
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            tags: Vec::new(),
+            age: 20,
+            nickname: None,
+        }
+    );
+
+    let proto: Proto = user.into();
+    assert_eq!(
+        proto,
+        Proto {
+            id: Some(1),
+            tags: Some(Vec::new()),
+            age: Some(20),
+            nickname: None,
+        }
+    );
+
+    let error = User::try_from(Proto::default()).unwrap_err();
+    assert_eq!(error.path, "id");
+    assert_eq!(
+        error.source().unwrap().downcast_ref::<MissingFieldError>(),
+        Some(&MissingFieldError { field: "id" })
+    );
+    assert_eq!(
+        error.to_string(),
+        "Failed to convert Proto to User at `id`: field id is missing"
+    );
+}
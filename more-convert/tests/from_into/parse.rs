@@ -0,0 +1,70 @@
+use std::{error::Error, net::IpAddr, num::ParseIntError};
+
+use more_convert::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from_into(Dto), into_ref(Dto))]
+pub struct Server {
+    // `parse` reads the string of the source, `display` writes the string of the target
+    #[convert(display)]
+    #[convert(from(Dto), parse)]
+    pub port: u16,
+    #[convert(display)]
+    #[convert(from(Dto), parse)]
+    pub addresses: Vec<IpAddr>,
+    #[convert(display, required)]
+    #[convert(from(Dto), parse)]
+    pub weight: u8,
+    pub name: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Dto {
+    pub port: String,
+    pub addresses: Vec<String>,
+    pub weight: Option<String>,
+    pub name: String,
+}
+
+#[test]
+pub fn main() {
+    let dto = Dto {
+        port: "8080".to_string(),
+        addresses: vec!["127.0.0.1".to_string(), "::1".to_string()],
+        weight: Some("3".to_string()),
+        name: "local".to_string(),
+    };
+    let server = Server::try_from(dto).unwrap();
+    assert_eq!(
+        server,
+        Server {
+            port: 8080,
+            addresses: vec!["127.0.0.1".parse().unwrap(), "::1".parse().unwrap()],
+            weight: 3,
+            name: "local".to_string(),
+        }
+    );
+
+    let expected = Dto {
+        port: "8080".to_string(),
+        addresses: vec!["127.0.0.1".to_string(), "::1".to_string()],
+        weight: Some("3".to_string()),
+        name: "local".to_string(),
+    };
+    assert_eq!(Dto::from(&server), expected);
+    assert_eq!(Dto::from(server), expected);
+
+    let dto = Dto {
+        port: "port".to_string(),
+        addresses: Vec::new(),
+        weight: None,
+        name: "local".to_string(),
+    };
+    let error = Server::try_from(dto).unwrap_err();
+    assert_eq!(error.path, "port");
+    assert!(error
+        .source()
+        .unwrap()
+        .downcast_ref::<ParseIntError>()
+        .is_some());
+}
@@ -0,0 +1,69 @@
+use more_convert::Convert;
+
+mod cents {
+    pub fn into_target(value: u64) -> f64 {
+        value as f64 / 100.0
+    }
+
+    pub fn from_target(value: f64) -> u64 {
+        (value * 100.0).round() as u64
+    }
+}
+
+mod ratio {
+    use std::num::TryFromIntError;
+
+    pub fn try_into_target(value: u16) -> Result<u8, TryFromIntError> {
+        u8::try_from(value / 10)
+    }
+
+    pub fn try_from_target(value: u8) -> Result<u16, TryFromIntError> {
+        Ok(u16::from(value) * 10)
+    }
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from_into(B))]
+pub struct A {
+    #[convert(with = cents)]
+    pub price: u64,
+    // `try_with` makes both conversions fallible
+    #[convert(try_with = self::ratio)]
+    pub ratio: u16,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct B {
+    pub price: f64,
+    pub ratio: u8,
+}
+
+#[test]
+pub fn main() {
+    let b = B::try_from(A {
+        price: 150,
+        ratio: 20,
+    })
+    .unwrap();
+    assert_eq!(
+        b,
+        B {
+            price: 1.5,
+            ratio: 2,
+        }
+    );
+    assert_eq!(
+        A::try_from(b).unwrap(),
+        A {
+            price: 150,
+            ratio: 20,
+        }
+    );
+
+    let error = B::try_from(A {
+        price: 0,
+        ratio: 10000,
+    })
+    .unwrap_err();
+    assert_eq!(error.path, "ratio");
+}
@@ -0,0 +1,29 @@
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into(Response<U>), generics(U))]
+pub enum Output<T> {
+    Found(T),
+    #[convert(rename = "NotFound")]
+    Missing {
+        id: u8,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Response<T> {
+    Found(T),
+    NotFound { id: u16 },
+}
+
+#[test]
+pub fn main() {
+    assert_eq!(
+        Response::<String>::from(Output::Found("found")),
+        Response::Found("found".to_string())
+    );
+    assert_eq!(
+        Response::<String>::from(Output::<&str>::Missing { id: 1 }),
+        Response::NotFound { id: 1 }
+    );
+}
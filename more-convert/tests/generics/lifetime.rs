@@ -0,0 +1,34 @@
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into(WrapperDto<U>), generics(U))]
+pub struct Wrapper<'a, T> {
+    pub name: &'a str,
+    pub value: T,
+    pub list: Vec<T>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WrapperDto<T> {
+    pub name: String,
+    pub value: T,
+    pub list: Vec<T>,
+}
+
+#[test]
+pub fn main() {
+    let dto: WrapperDto<u16> = Wrapper {
+        name: "name",
+        value: 1u8,
+        list: vec![2],
+    }
+    .into();
+    assert_eq!(
+        dto,
+        WrapperDto {
+            name: "name".to_string(),
+            value: 1,
+            list: vec![2],
+        }
+    );
+}
@@ -0,0 +1,73 @@
+use more_convert::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(WrapperDto<U>), generics(U))]
+#[convert(try_from(WrapperRaw<U>), generics(U))]
+pub struct Wrapper<T> {
+    pub value: T,
+    pub list: Vec<T>,
+    pub opt: Option<T>,
+    #[convert(map = value.count)]
+    pub count: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WrapperDto<T> {
+    pub value: T,
+    pub list: Vec<T>,
+    pub opt: Option<T>,
+    pub count: usize,
+}
+
+pub struct WrapperRaw<T> {
+    pub value: T,
+    pub list: Vec<T>,
+    pub opt: Option<T>,
+    pub count: usize,
+}
+
+#[test]
+pub fn main() {
+    let dto: WrapperDto<u16> = Wrapper {
+        value: 1u8,
+        list: vec![2],
+        opt: Some(3),
+        count: 4,
+    }
+    .into();
+    assert_eq!(
+        dto,
+        WrapperDto {
+            value: 1,
+            list: vec![2],
+            opt: Some(3),
+            count: 4,
+        }
+    );
+
+    let wrapper: Result<Wrapper<u8>, _> = WrapperRaw {
+        value: 1u16,
+        list: vec![2],
+        opt: None,
+        count: 4,
+    }
+    .try_into();
+    assert_eq!(
+        wrapper.unwrap(),
+        Wrapper {
+            value: 1,
+            list: vec![2],
+            opt: None,
+            count: 4,
+        }
+    );
+
+    let error = Wrapper::<u8>::try_from(WrapperRaw {
+        value: 1u16,
+        list: vec![256],
+        opt: None,
+        count: 4,
+    })
+    .unwrap_err();
+    assert_eq!(error.path, "list");
+}
@@ -0,0 +1,5 @@
+pub mod enum_generics;
+pub mod lifetime;
+pub mod mapping;
+pub mod normal;
+pub mod plain_target;
@@ -0,0 +1,30 @@
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into(WrapperDto<T>))]
+pub struct Wrapper<T: Clone> {
+    pub value: T,
+    pub count: u8,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WrapperDto<T> {
+    pub value: T,
+    pub count: u16,
+}
+
+#[test]
+pub fn main() {
+    let dto: WrapperDto<String> = Wrapper {
+        value: "value".to_string(),
+        count: 1,
+    }
+    .into();
+    assert_eq!(
+        dto,
+        WrapperDto {
+            value: "value".to_string(),
+            count: 1,
+        }
+    );
+}
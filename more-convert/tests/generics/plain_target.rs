@@ -0,0 +1,29 @@
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into(Summary))]
+pub struct Wrapper<T> {
+    #[convert(ignore)]
+    pub inner: T,
+    pub name: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Summary {
+    pub name: String,
+}
+
+#[test]
+pub fn main() {
+    let summary: Summary = Wrapper {
+        inner: vec![1u8],
+        name: "name".to_string(),
+    }
+    .into();
+    assert_eq!(
+        summary,
+        Summary {
+            name: "name".to_string(),
+        }
+    );
+}
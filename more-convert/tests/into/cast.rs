@@ -0,0 +1,65 @@
+use std::num::TryFromIntError;
+
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into(B), into_ref(B))]
+pub struct A {
+    #[convert(cast)]
+    pub cast: u64,
+    #[convert(saturating)]
+    pub saturating: Vec<i64>,
+    #[convert(round)]
+    pub round: Option<f64>,
+    #[convert(saturating)]
+    pub float: f64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct B {
+    pub cast: u32,
+    pub saturating: Vec<u8>,
+    pub round: Option<i32>,
+    pub float: f32,
+}
+
+// `checked` makes `into` fallible
+#[derive(Convert)]
+#[convert(into(D))]
+pub struct C {
+    #[convert(checked)]
+    pub checked: Vec<u64>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct D {
+    pub checked: Vec<u32>,
+}
+
+#[test]
+pub fn main() {
+    let a = A {
+        cast: u64::from(u32::MAX) + 2,
+        saturating: vec![-1, 1, 300],
+        round: Some(2.5),
+        float: f64::MAX,
+    };
+    let expected = B {
+        cast: 1,
+        saturating: vec![0, 1, 255],
+        round: Some(3),
+        float: f32::MAX,
+    };
+    assert_eq!(B::from(&a), expected);
+    assert_eq!(B::from(a), expected);
+
+    let c = C { checked: vec![1] };
+    assert_eq!(D::try_from(c).unwrap(), D { checked: vec![1] });
+
+    let c = C {
+        checked: vec![1, u64::MAX],
+    };
+    let error = D::try_from(c).unwrap_err();
+    assert_eq!(error.path, "checked");
+    assert!(error.source.downcast_ref::<TryFromIntError>().is_some());
+}
@@ -0,0 +1,125 @@
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into(User), constructor = User::new(name, age))]
+#[convert(into(UserView), builder)]
+#[convert(into(Name), constructor = Name::new(name))]
+pub struct UserRow {
+    pub name: String,
+    // every converted field must be used by the constructor
+    #[convert(into(Name), ignore)]
+    pub age: u8,
+}
+
+#[derive(Convert)]
+#[convert(into(Point), constructor = Point::new(_1, _0))]
+pub struct Row(i32, i32);
+
+#[derive(Debug, PartialEq)]
+pub struct User {
+    name: String,
+    age: u16,
+}
+
+impl User {
+    pub fn new(name: String, age: u16) -> Self {
+        Self {
+            name: name.to_uppercase(),
+            age,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Name(String);
+
+impl Name {
+    pub fn new(name: String) -> Self {
+        Self(name)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UserView {
+    label: String,
+}
+
+impl UserView {
+    pub fn builder() -> UserViewBuilder {
+        UserViewBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct UserViewBuilder {
+    name: String,
+    age: u8,
+}
+
+impl UserViewBuilder {
+    pub fn name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn age(mut self, age: u8) -> Self {
+        self.age = age;
+        self
+    }
+
+    pub fn build(self) -> UserView {
+        UserView {
+            label: format!("{} ({})", self.name, self.age),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+}
+
+#[test]
+pub fn main() {
+    let user: User = UserRow {
+        name: "jane".to_string(),
+        age: 30,
+    }
+    .into();
+    assert_eq!(
+        user,
+        User {
+            name: "JANE".to_string(),
+            age: 30,
+        }
+    );
+
+    let view: UserView = UserRow {
+        name: "jane".to_string(),
+        age: 30,
+    }
+    .into();
+    assert_eq!(
+        view,
+        UserView {
+            label: "jane (30)".to_string(),
+        }
+    );
+
+    let name: Name = UserRow {
+        name: "jane".to_string(),
+        age: 30,
+    }
+    .into();
+    assert_eq!(name, Name("jane".to_string()));
+
+    let point: Point = Row(1, 2).into();
+    assert_eq!(point, Point { x: 2, y: 1 });
+}
@@ -0,0 +1,84 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+use more_convert::Convert;
+
+pub type Ids = Vec<u8>;
+
+#[derive(Convert)]
+#[convert(into(B))]
+pub struct A {
+    pub map: HashMap<u8, Vec<u8>>,
+    pub set: HashSet<u8>,
+    pub boxed: Box<u8>,
+    // shared pointers are converted through their content only with the hint
+    #[convert(container = "Rc<_>")]
+    pub rc: Rc<u8>,
+    #[convert(container = "Arc<Option<_>>")]
+    pub arc: Arc<Option<u8>>,
+    // the shared state is passed as is
+    pub state: Arc<Mutex<u8>>,
+    pub array: [u8; 2],
+    pub nested: Option<Vec<u8>>,
+    pub result: Result<u8, u8>,
+    // the alias is not recognized as a `Vec` without the hint
+    #[convert(container = "Vec<_>")]
+    pub ids: Ids,
+    // converted as a whole with `_`
+    #[convert(container = "_")]
+    pub whole: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub struct B {
+    pub map: BTreeMap<u16, Vec<u16>>,
+    pub set: HashSet<u16>,
+    pub boxed: Box<u16>,
+    pub rc: Rc<u16>,
+    pub arc: Arc<Option<u16>>,
+    pub state: Arc<Mutex<u8>>,
+    pub array: [u16; 2],
+    pub nested: Option<Vec<u16>>,
+    pub result: Result<u16, u16>,
+    pub ids: Vec<u16>,
+    pub whole: Vec<u8>,
+}
+
+#[test]
+pub fn main() {
+    let shared = Rc::new(4);
+    let state = Arc::new(Mutex::new(12));
+    let a = A {
+        map: HashMap::from([(1, vec![2, 3])]),
+        set: HashSet::from([1]),
+        boxed: Box::new(2),
+        rc: Rc::clone(&shared),
+        arc: Arc::new(Some(5)),
+        state: Arc::clone(&state),
+        array: [6, 7],
+        nested: Some(vec![8]),
+        result: Err(9),
+        ids: vec![10],
+        whole: vec![11],
+    };
+
+    let b: B = a.into();
+    assert_eq!(b.map, BTreeMap::from([(1, vec![2, 3])]));
+    assert_eq!(b.set, HashSet::from([1]));
+    assert_eq!(b.boxed, Box::new(2));
+    assert_eq!(b.rc, Rc::new(4));
+    assert_eq!(b.arc, Arc::new(Some(5)));
+    assert_eq!(b.array, [6, 7]);
+    assert_eq!(b.nested, Some(vec![8]));
+    assert_eq!(b.result, Err(9));
+    assert_eq!(b.ids, vec![10]);
+    assert_eq!(b.whole, vec![11]);
+
+    // the shared content of the hinted `Rc` is cloned
+    assert_eq!(*shared, 4);
+    // the state is still shared
+    assert!(Arc::ptr_eq(&b.state, &state));
+}
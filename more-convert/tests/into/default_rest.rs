@@ -0,0 +1,54 @@
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into(B), default_rest)]
+pub struct A {
+    pub name: String,
+}
+
+#[derive(Convert)]
+#[convert(into(B), default_rest = B::with_note(value.name.clone()))]
+pub struct C {
+    pub name: String,
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct B {
+    pub name: String,
+    pub note: Option<String>,
+    pub count: u32,
+}
+
+impl B {
+    fn with_note(note: String) -> Self {
+        Self {
+            note: Some(note),
+            count: 1,
+            ..Default::default()
+        }
+    }
+}
+
+#[test]
+pub fn main() {
+    assert_eq!(
+        B::from(A {
+            name: "a".to_string()
+        }),
+        B {
+            name: "a".to_string(),
+            note: None,
+            count: 0,
+        }
+    );
+    assert_eq!(
+        B::from(C {
+            name: "c".to_string()
+        }),
+        B {
+            name: "c".to_string(),
+            note: Some("c".to_string()),
+            count: 1,
+        }
+    );
+}
@@ -1,6 +1,14 @@
+pub mod cast;
+pub mod constructor;
+pub mod container;
+pub mod default_rest;
 pub mod generate;
 pub mod ignore;
 pub mod map;
 pub mod multi;
 pub mod normal;
+pub mod partial_move;
 pub mod rename;
+pub mod split;
+pub mod target_path;
+pub mod via;
@@ -0,0 +1,53 @@
+use more_convert::Convert;
+
+// fields are moved before `map_struct` and `generate` in declaration order
+#[derive(Convert)]
+#[convert(into(B))]
+#[generate(B(len = value.name.len() + value.names.len()))]
+pub struct A {
+    pub name: String,
+    #[convert(map_field = join)]
+    pub names: Vec<String>,
+    #[convert(map_struct = describe)]
+    pub description: (),
+    #[convert(map = value.name.clone())]
+    pub copied: (),
+}
+
+fn join(names: Vec<String>) -> String {
+    names.join(",")
+}
+
+fn describe(a: &A) -> String {
+    format!("{}: {}", a.name, a.names.join(","))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct B {
+    name: String,
+    names: String,
+    description: String,
+    copied: String,
+    len: usize,
+}
+
+#[test]
+pub fn main() {
+    let a = A {
+        name: "a".to_string(),
+        names: vec!["b".to_string(), "c".to_string()],
+        description: (),
+        copied: (),
+    };
+
+    assert_eq!(
+        B::from(a),
+        B {
+            name: "a".to_string(),
+            names: "b,c".to_string(),
+            description: "a: b,c".to_string(),
+            copied: "a".to_string(),
+            len: 3,
+        }
+    );
+}
@@ -0,0 +1,42 @@
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into(Person), into_ref(Person))]
+pub struct User {
+    pub id: i64,
+    #[convert(split(
+        first_name = first_word(name),
+        last_name = name.split(' ').skip(1).collect::<Vec<_>>().join(" "),
+    ))]
+    pub name: String,
+}
+
+fn first_word(name: &str) -> String {
+    name.split(' ').next().unwrap_or_default().to_string()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Person {
+    pub id: i64,
+    pub first_name: String,
+    pub last_name: String,
+}
+
+#[test]
+pub fn main() {
+    let user = User {
+        id: 1,
+        name: "Ada Lovelace".to_string(),
+    };
+    let expected = Person {
+        id: 1,
+        first_name: "Ada".to_string(),
+        last_name: "Lovelace".to_string(),
+    };
+
+    let person: Person = (&user).into();
+    assert_eq!(person, expected);
+
+    let person: Person = user.into();
+    assert_eq!(person, expected);
+}
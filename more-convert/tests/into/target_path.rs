@@ -0,0 +1,42 @@
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into(B), default_rest)]
+pub struct A {
+    pub id: u16,
+    // assigned after `B` is constructed
+    #[convert(target_path = "meta.created_at")]
+    pub created_at: u32,
+}
+
+#[derive(Default, Debug, PartialEq)]
+pub struct B {
+    pub id: u32,
+    pub meta: Meta,
+}
+
+#[derive(Default, Debug, PartialEq)]
+pub struct Meta {
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+#[test]
+pub fn main() {
+    let a = A {
+        id: 1,
+        created_at: 100,
+    };
+
+    let b: B = a.into();
+    assert_eq!(
+        b,
+        B {
+            id: 1,
+            meta: Meta {
+                created_at: 100,
+                updated_at: 0,
+            },
+        }
+    );
+}
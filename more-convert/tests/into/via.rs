@@ -0,0 +1,56 @@
+use more_convert::Convert;
+
+#[derive(Convert, Clone)]
+#[convert(into(Domain))]
+#[convert(into(ApiDto), via = Domain)]
+#[convert(into(Summary), via(Domain, ApiDto))]
+#[convert(from(ApiDto), via = Domain)]
+pub struct DbRow {
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Convert)]
+#[convert(into(DbRow))]
+#[convert(from_into(ApiDto))]
+pub struct Domain {
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(Summary))]
+pub struct ApiDto {
+    pub id: i64,
+    #[convert(into(Summary), ignore)]
+    pub name: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Summary {
+    pub id: i64,
+}
+
+#[test]
+pub fn main() {
+    let row = DbRow {
+        id: 1,
+        name: "name".to_string(),
+    };
+
+    let dto: ApiDto = row.clone().into();
+    assert_eq!(
+        dto,
+        ApiDto {
+            id: 1,
+            name: "name".to_string(),
+        }
+    );
+
+    let summary: Summary = row.into();
+    assert_eq!(summary, Summary { id: 1 });
+
+    let row: DbRow = dto.into();
+    assert_eq!(row.id, 1);
+    assert_eq!(row.name, "name");
+}
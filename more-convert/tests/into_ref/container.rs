@@ -0,0 +1,49 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into_ref(B))]
+pub struct A {
+    #[convert(by_ref)]
+    pub map: BTreeMap<String, Option<Name>>,
+    #[convert(by_ref)]
+    pub array: [Name; 2],
+    #[convert(by_ref, container = "Arc<_>")]
+    pub arc: Arc<Name>,
+}
+
+// not `Clone`
+pub struct Name(String);
+
+impl From<&Name> for String {
+    fn from(value: &Name) -> Self {
+        value.0.clone()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct B {
+    pub map: BTreeMap<String, Option<String>>,
+    pub array: [String; 2],
+    pub arc: Arc<String>,
+}
+
+#[test]
+pub fn main() {
+    let a = A {
+        map: BTreeMap::from([("key".to_string(), Some(Name("map".to_string())))]),
+        array: [Name("first".to_string()), Name("second".to_string())],
+        arc: Arc::new(Name("arc".to_string())),
+    };
+
+    let b = B::from(&a);
+    assert_eq!(
+        b,
+        B {
+            map: BTreeMap::from([("key".to_string(), Some("map".to_string()))]),
+            array: ["first".to_string(), "second".to_string()],
+            arc: Arc::new("arc".to_string()),
+        }
+    );
+}
@@ -0,0 +1,34 @@
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into_ref(B))]
+pub enum A {
+    Unit,
+    Tuple(String),
+    Struct {
+        #[convert(map = value.to_string())]
+        value: u8,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum B {
+    Unit,
+    Tuple(String),
+    Struct { value: String },
+}
+
+#[test]
+pub fn main() {
+    assert_eq!(B::from(&A::Unit), B::Unit);
+    assert_eq!(
+        B::from(&A::Tuple("tuple".to_string())),
+        B::Tuple("tuple".to_string())
+    );
+    assert_eq!(
+        B::from(&A::Struct { value: 1 }),
+        B::Struct {
+            value: "1".to_string()
+        }
+    );
+}
@@ -0,0 +1,61 @@
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into_ref(B), into(B))]
+pub struct A {
+    #[convert(map = value.map.to_string())]
+    pub map: u8,
+    #[convert(map_struct = map_struct)]
+    pub map_struct: u8,
+    #[convert(map_field = map_field)]
+    pub map_field: String,
+    #[convert(into_ref(B), try_map_field = parse)]
+    #[convert(into(B), map = 0)]
+    pub parsed: String,
+}
+
+fn map_field(value: impl AsRef<str>) -> usize {
+    value.as_ref().len()
+}
+
+fn map_struct(a: &A) -> String {
+    a.map_struct.to_string()
+}
+
+fn parse(value: &str) -> Result<u8, std::num::ParseIntError> {
+    value.parse()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct B {
+    pub map: String,
+    pub map_field: usize,
+    pub map_struct: String,
+    pub parsed: u8,
+}
+
+#[test]
+pub fn main() {
+    let a = A {
+        map: 1,
+        map_field: "22".to_string(),
+        map_struct: 3,
+        parsed: "4".to_string(),
+    };
+    let expected = B {
+        map: "1".to_string(),
+        map_field: 2,
+        map_struct: "3".to_string(),
+        parsed: 4,
+    };
+
+    // `try_map_field` makes the borrowing conversion fallible
+    assert_eq!(B::try_from(&a).unwrap(), expected);
+    assert_eq!(
+        B::from(a),
+        B {
+            parsed: 0,
+            ..expected
+        }
+    );
+}
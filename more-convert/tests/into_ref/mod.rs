@@ -0,0 +1,5 @@
+pub mod container;
+pub mod enum_ref;
+pub mod map;
+pub mod normal;
+pub mod with;
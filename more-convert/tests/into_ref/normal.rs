@@ -0,0 +1,88 @@
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(into_ref(B))]
+pub struct A {
+    pub normal: u8,
+    pub name: String,
+    pub opt: Option<u8>,
+    pub vec: Vec<u8>,
+    // converted with `From<&Name>` instead of `Clone`
+    #[convert(by_ref)]
+    pub by_ref: Name,
+    #[convert(by_ref)]
+    pub by_ref_vec: Vec<Name>,
+}
+
+// not `Clone`
+pub struct Name(String);
+
+impl From<&Name> for String {
+    fn from(value: &Name) -> Self {
+        value.0.clone()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct B {
+    pub normal: u16,
+    pub name: String,
+    pub opt: Option<u16>,
+    pub vec: Vec<u16>,
+    pub by_ref: String,
+    pub by_ref_vec: Vec<String>,
+}
+
+#[derive(Convert)]
+#[convert(into_ref(WrapperDto<U>), generics(U))]
+pub struct Wrapper<T> {
+    pub value: T,
+    #[convert(by_ref)]
+    pub items: Vec<T>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WrapperDto<T> {
+    pub value: T,
+    pub items: Vec<T>,
+}
+
+#[test]
+pub fn main() {
+    let a = A {
+        normal: 1,
+        name: "name".to_string(),
+        opt: Some(2),
+        vec: vec![3],
+        by_ref: Name("by_ref".to_string()),
+        by_ref_vec: vec![Name("vec".to_string())],
+    };
+
+    let b = B::from(&a);
+    assert_eq!(
+        b,
+        B {
+            normal: 1,
+            name: "name".to_string(),
+            opt: Some(2),
+            vec: vec![3],
+            by_ref: "by_ref".to_string(),
+            by_ref_vec: vec!["vec".to_string()],
+        }
+    );
+
+    // `a` is still available
+    assert_eq!(a.name, "name");
+
+    let wrapper = Wrapper {
+        value: "value".to_string(),
+        items: vec!["item".to_string()],
+    };
+    assert_eq!(
+        WrapperDto::<String>::from(&wrapper),
+        WrapperDto {
+            value: "value".to_string(),
+            items: vec!["item".to_string()],
+        }
+    );
+}
@@ -0,0 +1,46 @@
+use more_convert::Convert;
+
+mod cents {
+    pub fn into_target(value: u64) -> f64 {
+        value as f64 / 100.0
+    }
+}
+
+mod label {
+    pub fn into_target(value: &str) -> usize {
+        value.len()
+    }
+}
+
+#[derive(Convert)]
+#[convert(into_ref(B))]
+pub struct A {
+    // the field is cloned, like the default conversion
+    #[convert(with = cents)]
+    pub price: u64,
+    // the module takes a reference with `by_ref`
+    #[convert(by_ref, with = label)]
+    pub label: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct B {
+    pub price: f64,
+    pub label: usize,
+}
+
+#[test]
+pub fn main() {
+    let a = A {
+        price: 150,
+        label: "label".to_string(),
+    };
+    assert_eq!(
+        B::from(&a),
+        B {
+            price: 1.5,
+            label: 5
+        }
+    );
+    assert_eq!(a.price, 150);
+}
@@ -0,0 +1,49 @@
+use more_convert::{Convert, ConvertError};
+
+pub mod other {
+    #[derive(Debug, PartialEq)]
+    pub enum Level {
+        Debug,
+        Info,
+        Warn { code: String },
+    }
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(remote = other::Level)]
+pub enum LevelDef {
+    Debug,
+    #[convert(rename = "Info")]
+    Information,
+    Warn {
+        #[convert(parse)]
+        #[convert(into(other::Level), display)]
+        code: u32,
+    },
+}
+
+#[test]
+pub fn main() {
+    assert_eq!(
+        LevelDef::from_remote(other::Level::Info).unwrap(),
+        LevelDef::Information
+    );
+    let level = LevelDef::from_remote(other::Level::Warn {
+        code: "42".to_string(),
+    })
+    .unwrap();
+    assert_eq!(level, LevelDef::Warn { code: 42 });
+    let error: ConvertError = LevelDef::from_remote(other::Level::Warn {
+        code: "x".to_string(),
+    })
+    .unwrap_err();
+    assert_eq!(error.path, "Warn.code");
+
+    assert_eq!(
+        LevelDef::to_remote(level),
+        other::Level::Warn {
+            code: "42".to_string()
+        }
+    );
+    assert_eq!(LevelDef::to_remote(LevelDef::Debug), other::Level::Debug);
+}
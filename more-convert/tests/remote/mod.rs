@@ -0,0 +1,2 @@
+pub mod enum_remote;
+pub mod normal;
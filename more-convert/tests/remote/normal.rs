@@ -0,0 +1,79 @@
+use more_convert::Convert;
+
+// a type of another crate, which can't implement `From` for another foreign type
+pub mod other {
+    #[derive(Debug, PartialEq)]
+    pub struct Config {
+        pub host: String,
+        pub port: u16,
+        pub timeout_ms: u64,
+        pub limits: Limits,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Limits {
+        pub max_connections: u32,
+    }
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(remote = other::Config)]
+#[generate(from(other::Config), address = format!("{}:{}", value.host, value.port))]
+pub struct ConfigDef {
+    pub host: String,
+    pub port: u16,
+    #[convert(rename = "timeout_ms")]
+    pub timeout: u64,
+    #[convert(into(other::Config), map_field = LimitsDef::to_remote)]
+    #[convert(from(other::Config), map_field = LimitsDef::from_remote)]
+    pub limits: LimitsDef,
+    // only in the mirror, computed by `generate`
+    #[convert(ignore)]
+    pub address: String,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(remote = other::Limits)]
+pub struct LimitsDef {
+    #[convert(map = value.max_connections.min(100))]
+    pub max_connections: u32,
+}
+
+#[test]
+pub fn main() {
+    let config = other::Config {
+        host: "localhost".to_string(),
+        port: 8080,
+        timeout_ms: 1000,
+        limits: other::Limits {
+            max_connections: 1000,
+        },
+    };
+
+    let def = ConfigDef::from_remote(config);
+    assert_eq!(
+        def,
+        ConfigDef {
+            host: "localhost".to_string(),
+            port: 8080,
+            timeout: 1000,
+            limits: LimitsDef {
+                max_connections: 100,
+            },
+            address: "localhost:8080".to_string(),
+        }
+    );
+
+    let config = ConfigDef::to_remote(def);
+    assert_eq!(
+        config,
+        other::Config {
+            host: "localhost".to_string(),
+            port: 8080,
+            timeout_ms: 1000,
+            limits: other::Limits {
+                max_connections: 100,
+            },
+        }
+    );
+}
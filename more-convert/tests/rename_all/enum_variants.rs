@@ -0,0 +1,30 @@
+#![allow(non_camel_case_types)]
+
+use more_convert::Convert;
+
+// the rules rename the variants of enums
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from_into(Status), rename_all = "snake_case")]
+pub enum ApiStatus {
+    Active,
+    InReview { reviewer: String },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    active,
+    in_review { reviewer: String },
+}
+
+#[test]
+pub fn main() {
+    assert_eq!(Status::from(ApiStatus::Active), Status::active);
+    assert_eq!(
+        ApiStatus::from(Status::in_review {
+            reviewer: "a".to_string()
+        }),
+        ApiStatus::InReview {
+            reviewer: "a".to_string()
+        }
+    );
+}
@@ -0,0 +1,2 @@
+pub mod enum_variants;
+pub mod normal;
@@ -0,0 +1,57 @@
+#![allow(non_snake_case)]
+
+use more_convert::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from_into(UserDto), rename_all = "camelCase")]
+#[convert(into(UserRow), prefix = "db_", suffix = "_col")]
+pub struct User {
+    pub user_id: u8,
+    pub display_name: String,
+    // `rename` overrides the rules
+    #[convert(rename = "mail")]
+    pub r#email: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UserDto {
+    pub userId: u8,
+    pub displayName: String,
+    pub mail: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UserRow {
+    pub db_user_id_col: u8,
+    pub db_display_name_col: String,
+    pub mail: String,
+}
+
+#[test]
+pub fn main() {
+    let user = User {
+        user_id: 1,
+        display_name: "name".to_string(),
+        email: "mail".to_string(),
+    };
+
+    let dto = UserDto::from(user);
+    assert_eq!(
+        dto,
+        UserDto {
+            userId: 1,
+            displayName: "name".to_string(),
+            mail: "mail".to_string(),
+        }
+    );
+
+    let user = User::from(dto);
+    assert_eq!(
+        UserRow::from(user),
+        UserRow {
+            db_user_id_col: 1,
+            db_display_name_col: "name".to_string(),
+            mail: "mail".to_string(),
+        }
+    );
+}
@@ -0,0 +1,59 @@
+use more_convert::Convert;
+
+#[derive(Convert, Debug)]
+#[convert(try_from(UserDto), collect_errors)]
+pub struct User {
+    pub age: u8,
+    #[convert(try_map = value.id.parse())]
+    pub id: u32,
+    pub name: String,
+    pub address: Address,
+}
+
+#[derive(Convert, Debug)]
+#[convert(try_from(AddressDto), collect_errors)]
+pub struct Address {
+    pub zip: u16,
+    pub number: u8,
+}
+
+pub struct UserDto {
+    age: i32,
+    id: String,
+    name: String,
+    address: AddressDto,
+}
+
+pub struct AddressDto {
+    zip: u32,
+    number: u32,
+}
+
+#[test]
+pub fn main() {
+    let dto = UserDto {
+        age: 20,
+        id: "1".to_string(),
+        name: "name".to_string(),
+        address: AddressDto { zip: 1, number: 2 },
+    };
+    let user = User::try_from(dto).unwrap();
+    assert_eq!(user.age, 20);
+    assert_eq!(user.address.number, 2);
+
+    let dto = UserDto {
+        age: -1,
+        id: "a".to_string(),
+        name: "name".to_string(),
+        address: AddressDto {
+            zip: 100_000,
+            number: 1000,
+        },
+    };
+    let errors = User::try_from(dto).unwrap_err();
+    let paths = errors
+        .into_iter()
+        .map(|error| error.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["age", "id", "address.zip", "address.number"]);
+}
@@ -0,0 +1,53 @@
+use std::error::Error;
+
+use more_convert::{Convert, TryFromEnumReprError};
+
+#[derive(Convert, Debug)]
+#[convert(try_from(UserDto))]
+pub struct User {
+    pub name: String,
+    pub address: Address,
+}
+
+#[derive(Convert, Debug)]
+#[convert(try_from(AddressDto))]
+pub struct Address {
+    #[convert(rename = "zip_code")]
+    pub zip: u16,
+}
+
+pub struct UserDto {
+    name: String,
+    address: AddressDto,
+}
+
+pub struct AddressDto {
+    zip_code: u32,
+}
+
+#[test]
+pub fn main() {
+    let dto = UserDto {
+        name: "name".to_string(),
+        address: AddressDto { zip_code: 100_000 },
+    };
+
+    let error = User::try_from(dto).unwrap_err();
+    assert_eq!(error.path, "address.zip_code");
+    assert_eq!(error.source_type, "UserDto");
+    assert_eq!(error.target_type, "User");
+    assert_eq!(
+        error.to_string(),
+        "Failed to convert UserDto to User at `address.zip_code`: out of range integral type conversion attempted"
+    );
+    assert!(error
+        .source()
+        .unwrap()
+        .downcast_ref::<std::num::TryFromIntError>()
+        .is_some());
+    assert!(error
+        .source()
+        .unwrap()
+        .downcast_ref::<TryFromEnumReprError>()
+        .is_none());
+}
@@ -0,0 +1,54 @@
+use more_convert::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(B))]
+pub struct A {
+    // value's type is `B`
+    #[convert(try_map = value.try_map.parse())]
+    try_map: u8,
+    #[convert(try_map_field = try_map_field)]
+    try_map_field: u8,
+    #[convert(map = value.map.len())]
+    map: usize,
+}
+
+fn try_map_field(try_map_field: String) -> Result<u8, String> {
+    try_map_field
+        .strip_prefix('#')
+        .ok_or_else(|| format!("missing `#` in {try_map_field}"))?
+        .parse()
+        .map_err(|e| format!("{e}"))
+}
+
+pub struct B {
+    try_map: String,
+    try_map_field: String,
+    map: String,
+}
+
+#[test]
+pub fn main() {
+    let b = B {
+        try_map: "1".to_string(),
+        try_map_field: "#2".to_string(),
+        map: "three".to_string(),
+    };
+    let a: A = b.try_into().unwrap();
+    assert_eq!(
+        a,
+        A {
+            try_map: 1,
+            try_map_field: 2,
+            map: 5,
+        }
+    );
+
+    let b = B {
+        try_map: "1".to_string(),
+        try_map_field: "2".to_string(),
+        map: String::new(),
+    };
+    let error = A::try_from(b).unwrap_err();
+    assert_eq!(error.path, "try_map_field");
+    assert_eq!(error.source.to_string(), "missing `#` in 2");
+}
@@ -0,0 +1,4 @@
+pub mod collect_errors;
+pub mod error;
+pub mod map;
+pub mod normal;
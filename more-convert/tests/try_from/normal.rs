@@ -0,0 +1,76 @@
+use more_convert::{Convert, EnumRepr};
+
+#[derive(EnumRepr, Clone, Copy, Debug, PartialEq)]
+#[repr(u8)]
+pub enum Kind {
+    Zero = 0,
+    One = 1,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(B))]
+pub struct A {
+    pub normal: u8,
+    // EnumRepr from its raw integer
+    pub kind: Kind,
+    // auto try_into of inner
+    pub opt: Option<u8>,
+    // auto try_into of inner
+    pub vec: Vec<u8>,
+}
+
+pub struct B {
+    normal: u16,
+    kind: u8,
+    opt: Option<u16>,
+    vec: Vec<u16>,
+}
+
+#[test]
+pub fn main() {
+    let b = B {
+        normal: 1,
+        kind: 1,
+        opt: Some(2),
+        vec: vec![3, 4],
+    };
+    let a: A = b.try_into().unwrap();
+    assert_eq!(
+        a,
+        A {
+            normal: 1,
+            kind: Kind::One,
+            opt: Some(2),
+            vec: vec![3, 4],
+        }
+    );
+
+    let b = B {
+        normal: 256,
+        kind: 0,
+        opt: None,
+        vec: vec![],
+    };
+    assert!(A::try_from(b).is_err());
+
+    let b = B {
+        normal: 0,
+        kind: 2,
+        opt: None,
+        vec: vec![],
+    };
+    let error = A::try_from(b).unwrap_err();
+    assert_eq!(error.path, "kind");
+    assert_eq!(
+        error.source.to_string(),
+        "Failed to convert value 2 to enum Kind"
+    );
+
+    let b = B {
+        normal: 0,
+        kind: 0,
+        opt: None,
+        vec: vec![1, 1000],
+    };
+    assert!(A::try_from(b).is_err());
+}
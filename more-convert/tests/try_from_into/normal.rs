@@ -0,0 +1,41 @@
+use more_convert::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from_into(B))]
+pub struct A {
+    pub sample: u8,
+    #[convert(from(B), try_map = value.text.parse())]
+    #[convert(into(B), map = value.text.to_string())]
+    pub text: u16,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct B {
+    sample: i32,
+    text: String,
+}
+
+#[test]
+pub fn main() {
+    let b = B {
+        sample: 1,
+        text: "2".to_string(),
+    };
+    let a: A = b.try_into().unwrap();
+    assert_eq!(a, A { sample: 1, text: 2 });
+
+    let b: B = a.try_into().unwrap();
+    assert_eq!(
+        b,
+        B {
+            sample: 1,
+            text: "2".to_string(),
+        }
+    );
+
+    let b = B {
+        sample: -1,
+        text: "2".to_string(),
+    };
+    assert!(A::try_from(b).is_err());
+}
@@ -0,0 +1,104 @@
+use std::error::Error;
+
+use more_convert::Convert;
+
+#[derive(Convert, Clone)]
+#[convert(try_into(Age), try_constructor = Age::new(years))]
+#[convert(try_into(Email), try_builder)]
+pub struct Form {
+    pub years: u32,
+    #[convert(try_into(Age), ignore)]
+    #[convert(try_into(Email), rename = "address")]
+    pub email: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Age(u8);
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error("invalid age {0}")]
+pub struct InvalidAge(u32);
+
+impl Age {
+    pub fn new(years: u32) -> Result<Self, InvalidAge> {
+        match u8::try_from(years) {
+            Ok(years) if years <= 150 => Ok(Self(years)),
+            _ => Err(InvalidAge(years)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Email {
+    address: String,
+    years: u32,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error("missing @")]
+pub struct InvalidEmail;
+
+impl Email {
+    pub fn builder() -> EmailBuilder {
+        EmailBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct EmailBuilder {
+    address: String,
+    years: u32,
+}
+
+impl EmailBuilder {
+    pub fn address(mut self, address: String) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn years(mut self, years: u32) -> Self {
+        self.years = years;
+        self
+    }
+
+    pub fn build(self) -> Result<Email, InvalidEmail> {
+        if !self.address.contains('@') {
+            return Err(InvalidEmail);
+        }
+        Ok(Email {
+            address: self.address,
+            years: self.years,
+        })
+    }
+}
+
+#[test]
+pub fn main() {
+    let form = Form {
+        years: 30,
+        email: "jane@example.com".to_string(),
+    };
+    assert_eq!(Age::try_from(form.clone()).unwrap(), Age(30));
+    assert_eq!(
+        Email::try_from(form).unwrap(),
+        Email {
+            address: "jane@example.com".to_string(),
+            years: 30,
+        }
+    );
+
+    let form = Form {
+        years: 200,
+        email: "jane".to_string(),
+    };
+    let error = Age::try_from(form.clone()).unwrap_err();
+    assert_eq!(error.path, "(constructor)");
+    assert_eq!(
+        error.source().unwrap().downcast_ref::<InvalidAge>(),
+        Some(&InvalidAge(200))
+    );
+
+    let error = Email::try_from(form).unwrap_err();
+    assert_eq!(error.path, "(builder)");
+    assert!(error.source().unwrap().is::<InvalidEmail>());
+}
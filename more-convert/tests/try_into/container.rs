@@ -0,0 +1,69 @@
+use std::{collections::HashMap, error::Error};
+
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(try_into(B))]
+pub struct A {
+    pub map: HashMap<String, i64>,
+    pub array: [i64; 2],
+    pub nested: Option<Vec<i64>>,
+    pub boxed: Box<i64>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct B {
+    pub map: HashMap<String, u8>,
+    pub array: [u8; 2],
+    pub nested: Option<Vec<u8>>,
+    pub boxed: Box<u8>,
+}
+
+#[test]
+pub fn main() {
+    let a = A {
+        map: HashMap::from([("key".to_string(), 1)]),
+        array: [2, 3],
+        nested: Some(vec![4]),
+        boxed: Box::new(5),
+    };
+    let b: B = a.try_into().unwrap();
+    assert_eq!(
+        b,
+        B {
+            map: HashMap::from([("key".to_string(), 1)]),
+            array: [2, 3],
+            nested: Some(vec![4]),
+            boxed: Box::new(5),
+        }
+    );
+
+    let a = A {
+        map: HashMap::new(),
+        array: [2, 3],
+        nested: Some(vec![4, -1]),
+        boxed: Box::new(5),
+    };
+    let error = B::try_from(a).unwrap_err();
+    assert_eq!(error.path, "nested");
+    assert!(error
+        .source()
+        .unwrap()
+        .downcast_ref::<std::num::TryFromIntError>()
+        .is_some());
+
+    // the errors of the elements of maps are boxed
+    let a = A {
+        map: HashMap::from([("key".to_string(), 256)]),
+        array: [2, 3],
+        nested: None,
+        boxed: Box::new(5),
+    };
+    let error = B::try_from(a).unwrap_err();
+    assert_eq!(error.path, "map");
+    assert!(error
+        .source()
+        .unwrap()
+        .downcast_ref::<std::num::TryFromIntError>()
+        .is_some());
+}
@@ -0,0 +1,38 @@
+use more_convert::Convert;
+
+// `try_map` makes the conversion fallible even with `into`
+#[derive(Convert)]
+#[convert(into(B))]
+pub struct A {
+    #[convert(try_map = value.number.parse::<u8>())]
+    pub number: String,
+    pub normal: u8,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct B {
+    number: u8,
+    normal: u16,
+}
+
+#[test]
+pub fn main() {
+    let a = A {
+        number: "1".to_string(),
+        normal: 2,
+    };
+    let b: B = a.try_into().unwrap();
+    assert_eq!(
+        b,
+        B {
+            number: 1,
+            normal: 2
+        }
+    );
+
+    let a = A {
+        number: "a".to_string(),
+        normal: 2,
+    };
+    assert!(B::try_from(a).is_err());
+}
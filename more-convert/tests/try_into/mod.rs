@@ -0,0 +1,5 @@
+pub mod constructor;
+pub mod container;
+pub mod map;
+pub mod normal;
+pub mod via;
@@ -0,0 +1,41 @@
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(try_into(B))]
+pub struct A {
+    pub normal: i64,
+    #[convert(rename = "sample")]
+    pub hey: u8,
+    #[convert(ignore)]
+    pub ignored: u8,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct B {
+    normal: u32,
+    sample: u16,
+}
+
+#[test]
+pub fn main() {
+    let a = A {
+        normal: 1,
+        hey: 2,
+        ignored: 3,
+    };
+    let b: B = a.try_into().unwrap();
+    assert_eq!(
+        b,
+        B {
+            normal: 1,
+            sample: 2
+        }
+    );
+
+    let a = A {
+        normal: -1,
+        hey: 2,
+        ignored: 3,
+    };
+    assert!(B::try_from(a).is_err());
+}
@@ -0,0 +1,32 @@
+use std::error::Error;
+
+use more_convert::Convert;
+
+#[derive(Convert)]
+#[convert(try_into(Domain))]
+#[convert(try_into(ApiDto), via = Domain)]
+pub struct DbRow {
+    pub id: i64,
+}
+
+#[derive(Convert)]
+#[convert(into(ApiDto))]
+pub struct Domain {
+    pub id: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ApiDto {
+    pub id: u64,
+}
+
+#[test]
+pub fn main() {
+    let dto = ApiDto::try_from(DbRow { id: 1 }).unwrap();
+    assert_eq!(dto, ApiDto { id: 1 });
+
+    let error = ApiDto::try_from(DbRow { id: -1 }).unwrap_err();
+    // the path of the nested error is prefixed with the failed hop
+    assert_eq!(error.path, "Domain.id");
+    assert!(error.source().unwrap().is::<std::num::TryFromIntError>());
+}
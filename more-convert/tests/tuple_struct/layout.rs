@@ -0,0 +1,27 @@
+use more_convert::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(Named))]
+pub struct Tuple(#[convert(name = "id")] u8, #[convert(name = "name")] String);
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(Tuple))]
+pub struct Named {
+    #[convert(index = 1)]
+    name: String,
+    #[convert(index = 0)]
+    id: u8,
+}
+
+#[test]
+pub fn main() {
+    let named = Named::from(Tuple(1, "one".to_string()));
+    assert_eq!(
+        named,
+        Named {
+            id: 1,
+            name: "one".to_string(),
+        }
+    );
+    assert_eq!(Tuple::from(named), Tuple(1, "one".to_string()));
+}
@@ -0,0 +1,36 @@
+use more_convert::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from_into(B))]
+pub struct A(
+    u8,
+    #[convert(into(B), map_field = to_string)]
+    #[convert(from(B), map_field = parse)]
+    u8,
+);
+
+fn to_string(value: u8) -> String {
+    value.to_string()
+}
+
+fn parse(value: String) -> u8 {
+    value.parse().unwrap_or_default()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct B(u8, String);
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from_into(D))]
+pub struct C;
+
+#[derive(Debug, PartialEq)]
+pub struct D;
+
+#[test]
+pub fn main() {
+    assert_eq!(B::from(A(1, 2)), B(1, "2".to_string()));
+    assert_eq!(A::from(B(1, "2".to_string())), A(1, 2));
+    assert_eq!(C::from(D), C);
+    assert_eq!(D::from(C), D);
+}
@@ -0,0 +1,33 @@
+use more_convert::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(transparent)]
+pub struct UserId(u64);
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(transparent, into(Email))]
+pub struct UserName {
+    value: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Email {
+    value: String,
+}
+
+#[test]
+pub fn main() {
+    assert_eq!(UserId::from(1), UserId(1));
+    assert_eq!(u64::from(UserId(1)), 1);
+
+    let name: UserName = "name".to_string().into();
+    assert_eq!(String::from(name), "name");
+
+    let email: Email = UserName::from("a@b".to_string()).into();
+    assert_eq!(
+        email,
+        Email {
+            value: "a@b".to_string()
+        }
+    );
+}
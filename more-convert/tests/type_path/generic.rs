@@ -0,0 +1,61 @@
+use more_convert::Convert;
+
+#[derive(Debug, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Response<T> {
+    Ok(T),
+    NotFound,
+}
+
+#[derive(Convert)]
+#[convert(into(Page<u16>, Page<u32>))]
+pub struct Items {
+    #[convert(into(Page<u32>), map = value.items.into_iter().map(u32::from).rev().collect())]
+    pub items: Vec<u8>,
+    pub total: u32,
+}
+
+#[derive(Convert)]
+#[convert(into(Response<String>))]
+pub enum Found {
+    Ok(&'static str),
+    NotFound,
+}
+
+#[test]
+pub fn main() {
+    assert_eq!(
+        Page::<u16>::from(Items {
+            items: vec![1, 2],
+            total: 2,
+        }),
+        Page {
+            items: vec![1, 2],
+            total: 2,
+        }
+    );
+    assert_eq!(
+        Page::<u32>::from(Items {
+            items: vec![1, 2],
+            total: 2,
+        }),
+        Page {
+            items: vec![2, 1],
+            total: 2,
+        }
+    );
+
+    assert_eq!(
+        Response::<String>::from(Found::Ok("ok")),
+        Response::Ok("ok".to_string())
+    );
+    assert_eq!(
+        Response::<String>::from(Found::NotFound),
+        Response::NotFound
+    );
+}
@@ -0,0 +1,2 @@
+pub mod generic;
+pub mod path;
@@ -0,0 +1,54 @@
+use more_convert::Convert;
+
+mod dto {
+    #[derive(Debug, PartialEq)]
+    pub struct User {
+        pub id: u16,
+        pub name: String,
+        pub is_admin: bool,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum Role {
+        Admin,
+        Member,
+    }
+}
+
+#[derive(Convert)]
+#[convert(into(dto::User))]
+#[generate(dto::User(is_admin = value.role == Role::Admin))]
+pub struct User {
+    pub id: u8,
+    // the filter matches the struct-level target
+    #[convert(into(dto::User), rename = "name")]
+    pub user_name: String,
+    #[convert(ignore)]
+    pub role: Role,
+}
+
+#[derive(Convert, PartialEq)]
+#[convert(into(crate::type_path::path::dto::Role))]
+pub enum Role {
+    Admin,
+    Member,
+}
+
+#[test]
+pub fn main() {
+    let user = User {
+        id: 1,
+        user_name: "name".to_string(),
+        role: Role::Admin,
+    };
+    assert_eq!(
+        dto::User::from(user),
+        dto::User {
+            id: 1,
+            name: "name".to_string(),
+            is_admin: true,
+        }
+    );
+
+    assert_eq!(dto::Role::from(Role::Member), dto::Role::Member);
+}